@@ -0,0 +1,131 @@
+// Compiler wrapper extracting the MIR of the crates of a workspace while
+// cargo builds them:
+//
+//     RUSTC_WORKSPACE_WRAPPER=mir-parser-driver cargo build
+//
+// Cargo runs `mir-parser-driver <rustc> <args>` for each workspace crate. The
+// real compiler builds the crate first, so the artifacts and diagnostics are
+// those of a normal build; the crate is then compiled again in-process up to
+// the analysis and its MIR written next to the artifacts, to
+// `<out-dir>/<crate name><extra filename>.mir.json`. The phase is read from
+// `$MIR_PARSER_PHASE` (`built`, `promoted`, `drops-elaborated` or `optimized`,
+// `optimized` by default), any other value is an error.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::{Command, exit};
+
+use mir_parser::mir_types::MirPhase;
+
+fn phase() -> MirPhase {
+    match std::env::var("MIR_PARSER_PHASE").as_deref() {
+        Ok("built") => MirPhase::Built,
+        Ok("promoted") => MirPhase::Promoted,
+        Ok("drops-elaborated") => MirPhase::DropsElaborated,
+        Ok("optimized") | Err(_) => MirPhase::Optimized,
+        Ok(phase) => {
+            eprintln!(
+                "mir-parser-driver: unknown MIR_PARSER_PHASE `{}`, expected built, promoted, \
+                 drops-elaborated or optimized",
+                phase,
+            );
+            exit(1)
+        }
+    }
+}
+
+// Value of the option `flag`, given as `flag value` or `flag=value`.
+fn option<'a>(args : &'a [String], flag : &str) -> Option<&'a str> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        if arg == flag {
+            args.get(i + 1).map(String::as_str)
+        } else {
+            arg.strip_prefix(flag)?.strip_prefix('=')
+        }
+    })
+}
+
+// Value of the codegen option `name`, given as `-C name=value` or
+// `-Cname=value`.
+fn codegen_option<'a>(args : &'a [String], name : &str) -> Option<&'a str> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        let flag = if arg == "-C" { args.get(i + 1)? } else { arg.strip_prefix("-C")? };
+        flag.strip_prefix(name)?.strip_prefix('=')
+    })
+}
+
+// Options of the real compilation the in-process one drops: its lints and
+// diagnostics were already reported, and the outputs and incremental cache
+// belong to the real compiler.
+const DROPPED_OPTIONS : [&str; 4] = ["--cap-lints", "--emit", "--error-format", "--json"];
+
+// Command line of the in-process compilation.
+fn extraction_args(rustc : &str, args : &[String]) -> Vec<String> {
+    let mut extraction = vec![rustc.to_string()];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let incremental = |flag : &String| flag.starts_with("incremental=");
+        let joined = |option : &&str| arg.strip_prefix(*option).is_some_and(|v| v.starts_with('='));
+        if DROPPED_OPTIONS.contains(&arg.as_str())
+            || (arg == "-C" && args.as_slice().first().is_some_and(incremental))
+        {
+            args.next();
+        } else if !DROPPED_OPTIONS.iter().any(joined) && !arg.starts_with("-Cincremental=") {
+            extraction.push(arg.clone());
+        }
+    }
+    extraction.extend(["--cap-lints".to_string(), "allow".to_string()]);
+    // The driver is not installed in a toolchain, it uses the sysroot of the
+    // compiler it wraps.
+    if option(&extraction, "--sysroot").is_none()
+        && let Ok(output) = Command::new(rustc).args(["--print", "sysroot"]).output()
+    {
+        let sysroot = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        extraction.extend(["--sysroot".to_string(), sysroot]);
+    }
+    extraction
+}
+
+fn main() {
+    let phase = phase();
+    let args : Vec<String> = std::env::args().skip(1).collect();
+    let Some((rustc, args)) = args.split_first() else {
+        eprintln!("usage: mir-parser-driver <rustc> [<args>...]");
+        exit(1);
+    };
+    let status = Command::new(rustc).args(args).status().unwrap_or_else(|e| {
+        eprintln!("mir-parser-driver: cannot run {}: {}", rustc, e);
+        exit(1)
+    });
+    if !status.success() {
+        exit(status.code().unwrap_or(1));
+    }
+
+    // Queries such as `-vV` or `--print` compile nothing.
+    let (Some(crate_name), Some(out_dir)) =
+        (option(args, "--crate-name"), option(args, "--out-dir"))
+    else {
+        return;
+    };
+    if option(args, "--print").is_some() {
+        return;
+    }
+    let extra_filename = codegen_option(args, "extra-filename").unwrap_or("");
+    let path = PathBuf::from(out_dir).join(format!("{}{}.mir.json", crate_name, extra_filename));
+
+    let Some(crate_mir) = mir_parser::extract_with_args(&extraction_args(rustc, args), phase, false)
+    else {
+        eprintln!("mir-parser-driver: extraction of {} failed", crate_name);
+        exit(1);
+    };
+    let written = File::create(&path).map_err(|e| e.to_string()).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, &crate_mir).map_err(|e| e.to_string())?;
+        writer.flush().map_err(|e| e.to_string())
+    });
+    if let Err(e) = written {
+        eprintln!("mir-parser-driver: {}: {}", path.display(), e);
+        exit(1);
+    }
+}
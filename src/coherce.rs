@@ -1,7 +1,10 @@
 use rustc_span::def_id;
 
 use crate::mir_types;
+use crate::unsafety;
 use rustc_middle::{mir, ty};
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_middle::ty::util::IntTypeExt;
 
 pub trait Coherce<'tcx> : Sized  {
     type T;
@@ -12,6 +15,215 @@ pub trait Coherce<'tcx> : Sized  {
     ) -> Self::T;
 }
 
+// Path of a definition prefixed with the name of its crate, e.g.
+// `core::option::Option` or `foo::{impl#0}::new`, so that the same item has
+// the same path in the output of every crate.
+pub fn def_path(tcx : ty::TyCtxt<'_>, did : def_id::DefId) -> String {
+    format!("{}{}", tcx.crate_name(did.krate), tcx.def_path(did).to_string_no_crate_verbose())
+}
+
+// `Slice` constants hold their bytes directly rather than an `AllocId`; give
+// them one, deduplicated so that the same slice always gets the same id.
+pub fn slice_alloc_id<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    data : mir::interpret::ConstAllocation<'tcx>,
+) -> mir::interpret::AllocId {
+    tcx.reserve_and_set_memory_dedup(data, 0)
+}
+
+// Value of an unevaluated constant of the body of `def_id`. Only monomorphic
+// constants can be evaluated here, the others depend on the instantiation of
+// the enclosing item. The crate is already analysed when bodies of the early
+// phases are lowered, so evaluation triggers no borrowck or diagnostics of
+// its own; their constants are normalized first, as they still contain
+// projections.
+pub fn eval_unevaluated<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    def_id : def_id::DefId,
+    constant : &mir::ConstOperand<'tcx>,
+) -> Option<mir::ConstValue<'tcx>> {
+    let mir::Const::Unevaluated(uv, _) = constant.const_ else {
+        return None;
+    };
+    if uv.args.has_non_region_param() {
+        return None;
+    }
+    let typing_env = ty::TypingEnv::post_analysis(tcx, def_id);
+    let const_ = tcx.try_normalize_erasing_regions(typing_env, constant.const_).ok()?;
+    const_.eval(tcx, typing_env, constant.span).ok()
+}
+
+// Closures and anonymous constants have no visibility of their own, they
+// share the one of the item they are nested in.
+fn enclosing_item(tcx : ty::TyCtxt<'_>, did : def_id::DefId) -> def_id::DefId {
+    use rustc_hir::def::DefKind;
+    match tcx.def_kind(did) {
+        DefKind::Closure
+        | DefKind::InlineConst
+        | DefKind::AnonConst
+        | DefKind::SyntheticCoroutineBody => enclosing_item(tcx, tcx.parent(did)),
+        _ => did,
+    }
+}
+
+pub fn body_header(
+    tcx : ty::TyCtxt<'_>,
+    did : def_id::DefId,
+    promoted : Option<mir::Promoted>,
+) -> mir_types::BodyHeader {
+    use rustc_hir::def::DefKind;
+    let kind = tcx.def_kind(did);
+    let is_fn = matches!(kind, DefKind::Fn | DefKind::AssocFn);
+    mir_types::BodyHeader {
+        def_path : def_path(tcx, did),
+        name : tcx.opt_item_name(did).map(|s| s.to_string()),
+        kind : kind.to_mmir(tcx, did),
+        parent : tcx.opt_parent(did).map(|p| def_path(tcx, p)),
+        visibility : tcx.visibility(enclosing_item(tcx, did)).to_mmir(tcx, did),
+        is_unsafe : is_fn && tcx.fn_sig(did).skip_binder().safety().is_unsafe(),
+        is_const : is_fn && tcx.is_const_fn(did),
+        is_async : is_fn && tcx.asyncness(did).is_async(),
+        promoted : promoted.map(|p| p.as_u32()),
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for rustc_hir::def::DefKind {
+    type T = mir_types::DefKind;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use rustc_hir::def::DefKind as HirDefKind;
+        use mir_types::DefKind;
+        match self {
+            HirDefKind::Fn => DefKind::Fn,
+            HirDefKind::AssocFn => DefKind::AssocFn,
+            HirDefKind::Closure => DefKind::Closure,
+            HirDefKind::Const => DefKind::Const,
+            HirDefKind::AssocConst => DefKind::AssocConst,
+            HirDefKind::Static { mutability, .. } => {
+                DefKind::Static(mutability.to_mmir(tcx, def_id))
+            }
+            HirDefKind::AnonConst => DefKind::AnonConst,
+            HirDefKind::InlineConst => DefKind::InlineConst,
+            HirDefKind::Ctor(..) => DefKind::Ctor,
+            HirDefKind::SyntheticCoroutineBody => DefKind::SyntheticCoroutineBody,
+            other => DefKind::Other(other.descr(def_id).to_string()),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::Visibility<def_id::DefId> {
+    type T = mir_types::Visibility;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir_types::Visibility;
+        match self {
+            ty::Visibility::Public => Visibility::Public,
+            ty::Visibility::Restricted(module) => Visibility::Restricted(def_path(tcx, *module)),
+        }
+    }
+}
+
+fn location(sm : &rustc_span::source_map::SourceMap, pos : rustc_span::BytePos) -> mir_types::Location {
+    let loc = sm.lookup_char_pos(pos);
+    mir_types::Location {
+        file : sm.lookup_source_file_idx(pos) as u32,
+        line : loc.line as u32,
+        col : loc.col.0 as u32,
+    }
+}
+
+// Span without its macro backtrace.
+fn resolve_span(sm : &rustc_span::source_map::SourceMap, span : rustc_span::Span) -> mir_types::Span {
+    mir_types::Span {
+        lo : span.lo().0,
+        hi : span.hi().0,
+        start_loc : location(sm, span.lo()),
+        end_loc : location(sm, span.hi()),
+        from_expansion : span.from_expansion(),
+        backtrace : Vec::new(),
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for rustc_span::Span {
+    type T = mir_types::Span;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use rustc_span::hygiene::{ExpnKind, MacroKind};
+        use mir_types::ExpansionKind;
+        let sm = tcx.sess.source_map();
+        let backtrace = self
+            .macro_backtrace()
+            .map(|expn| mir_types::Expansion {
+                kind : match expn.kind {
+                    ExpnKind::Root => ExpansionKind::Root,
+                    ExpnKind::Macro(MacroKind::Bang, _) => ExpansionKind::MacroBang,
+                    ExpnKind::Macro(MacroKind::Attr, _) => ExpansionKind::MacroAttr,
+                    ExpnKind::Macro(MacroKind::Derive, _) => ExpansionKind::MacroDerive,
+                    ExpnKind::AstPass(_) => ExpansionKind::AstPass,
+                    ExpnKind::Desugaring(_) => ExpansionKind::Desugaring,
+                },
+                name : expn.kind.descr(),
+                // The outer expansions of the call site follow in the
+                // backtrace.
+                call_site : resolve_span(sm, expn.call_site),
+            })
+            .collect();
+        mir_types::Span { backtrace, ..resolve_span(sm, *self) }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::CanonicalUserTypeAnnotation<'tcx> {
+    type T = mir_types::UserTypeAnnotation;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        mir_types::UserTypeAnnotation {
+            user_ty : self.user_ty.value.kind.to_mmir(tcx, def_id),
+            span : self.span.to_mmir(tcx, def_id),
+            inferred_ty : self.inferred_ty.to_mmir(tcx, def_id),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::UserTypeKind<'tcx> {
+    type T = mir_types::UserTypeKind;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::UserTypeKind as MirUserTypeKind;
+        use mir_types::UserTypeKind;
+        match self {
+            MirUserTypeKind::Ty(ty) => UserTypeKind::Ty(ty.to_mmir(tcx, def_id)),
+            MirUserTypeKind::TypeOf(did, user_args) => UserTypeKind::TypeOf(
+                def_path(tcx, *did),
+                user_args.args.to_mmir(tcx, def_id),
+                user_args.user_self_ty.as_ref().map(|self_ty| mir_types::UserSelfTy {
+                    impl_def_path : def_path(tcx, self_ty.impl_def_id),
+                    self_ty : self_ty.self_ty.to_mmir(tcx, def_id),
+                }),
+            ),
+        }
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for mir::Body<'tcx> {
     type T = mir_types::Body;
 
@@ -20,13 +232,77 @@ impl<'tcx> Coherce<'tcx> for mir::Body<'tcx> {
             tcx : ty::TyCtxt<'tcx>,
             def_id : def_id::DefId,
         ) -> Self::T {
+        let mut unsafety = unsafety::UnsafetyMap::new(tcx, self, def_id);
+        let mut stmts = self.basic_blocks.to_mmir(tcx, def_id);
+        unsafety.annotate(&mut stmts);
         mir_types::Body {
-            stmts : self.basic_blocks.to_mmir(tcx, def_id),
+            header : body_header(tcx, def_id, self.source.promoted),
+            phase : self.phase.to_mmir(tcx, def_id),
+            stmts,
             local_decls: self.local_decls.to_mmir(tcx, def_id),
             arg_count: self.arg_count as usize,
             var_debug_info: self.var_debug_info.to_mmir(tcx, def_id),
             spread_arg: self.spread_arg.map(|s| s.as_usize()),
-            span: mir_types::Span::Span(self.span.lo().0, self.span.hi().0),
+            span: self.span.to_mmir(tcx, def_id),
+            user_type_annotations: self
+                .user_type_annotations
+                .iter()
+                .map(|x| x.to_mmir(tcx, def_id))
+                .collect(),
+            source_scopes: self.source_scopes.to_mmir(tcx, def_id),
+            unsafe_ops: unsafety.unsafe_ops(),
+            // Promoted bodies are not reachable from the body itself, the
+            // caller fills them in.
+            promoted: Vec::new(),
+        }
+    }
+}
+
+// Closest extraction phase of a body. Drop elaboration already lowers MIR to
+// runtime MIR, the runtime phases before optimization are `DropsElaborated`.
+impl<'tcx> Coherce<'tcx> for mir::MirPhase {
+    type T = mir_types::MirPhase;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::{MirPhase as MirMirPhase, RuntimePhase};
+        use mir_types::MirPhase;
+        match self {
+            MirMirPhase::Built => MirPhase::Built,
+            MirMirPhase::Analysis(_) => MirPhase::Promoted,
+            MirMirPhase::Runtime(RuntimePhase::Optimized) => MirPhase::Optimized,
+            MirMirPhase::Runtime(_) => MirPhase::DropsElaborated,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::SourceScopeData<'tcx> {
+    type T = mir_types::SourceScope;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        // The lint root is only kept for bodies of the local crate.
+        let lint_root = match &self.local_data {
+            mir::ClearCrossCrate::Set(data) => Some(mir_types::HirId {
+                owner : def_path(tcx, data.lint_root.owner.to_def_id()),
+                local_id : data.lint_root.local_id.as_u32(),
+            }),
+            mir::ClearCrossCrate::Clear => None,
+        };
+        mir_types::SourceScope {
+            span : self.span.to_mmir(tcx, def_id),
+            parent : self.parent_scope.map(|s| s.as_u32()),
+            inlined : self.inlined.map(|(instance, call_site)| {
+                (instance.to_mmir(tcx, def_id), call_site.to_mmir(tcx, def_id))
+            }),
+            inlined_parent : self.inlined_parent_scope.map(|s| s.as_u32()),
+            lint_root,
         }
     }
 }
@@ -107,7 +383,7 @@ impl<'tcx> Coherce<'tcx> for mir::VarDebugInfo<'tcx> {
         def_id: def_id::DefId,
     ) -> Self::T {
         mir_types::VarDebugInfo {
-            value : self.value.to_mmir(tcx, def_id),
+            content : self.value.to_mmir(tcx, def_id),
             scope : self.source_info.scope.as_u32(),
             name: self.name.to_string(),
             arg_index: self.argument_index.map(|i| i as u32),
@@ -174,7 +450,15 @@ impl<'tcx> Coherce<'tcx> for mir::ConstOperand<'tcx> {
             MirConst::Val(const_val, ty) => {
                 Const::Val(const_val.to_mmir(tcx, def_id), Box::new(ty.to_mmir(tcx, def_id)))
             },
-            _ => Const::Unknown,
+            MirConst::Unevaluated(uv, ty) => {
+                Const::Unevaluated(mir_types::UnevaluatedConst {
+                    def_path : def_path(tcx, uv.def),
+                    args : uv.args.to_mmir(tcx, def_id),
+                    promoted : uv.promoted.map(|p| p.as_u32()),
+                    value : eval_unevaluated(tcx, def_id, self).map(|v| v.to_mmir(tcx, def_id)),
+                    typ : Box::new(ty.to_mmir(tcx, def_id)),
+                })
+            },
         }
     }
 }
@@ -225,6 +509,32 @@ impl<'tcx> Coherce<'tcx> for mir::PlaceElem<'tcx> {
     }
 }
 
+impl<'tcx> Coherce<'tcx> for mir::ProjectionKind {
+    type T = mir_types::ProjectionKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::ProjectionElem;
+        use mir_types::ProjectionKind;
+        match self {
+            ProjectionElem::Deref => ProjectionKind::Deref,
+            ProjectionElem::Field(idx, ()) => ProjectionKind::Field(idx.as_u32()),
+            ProjectionElem::Index(()) => ProjectionKind::Index,
+            ProjectionElem::ConstantIndex { offset, min_length, from_end } =>
+                ProjectionKind::ConstantIndex(*offset as u32, *min_length as u32, *from_end),
+            ProjectionElem::Subslice { from, to, from_end } =>
+                ProjectionKind::Subslice(*from as u32, *to as u32, *from_end),
+            ProjectionElem::Downcast(_, idx) => ProjectionKind::Downcast(idx.as_u32()),
+            ProjectionElem::OpaqueCast(()) => ProjectionKind::OpaqueCast,
+            ProjectionElem::UnwrapUnsafeBinder(()) => ProjectionKind::UnwrapUnsafeBinder,
+            ProjectionElem::Subtype(()) => ProjectionKind::Subtype,
+        }
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for ty::Ty<'tcx> {
     type T = mir_types::Typ;
 
@@ -264,11 +574,275 @@ impl<'tcx> Coherce<'tcx> for ty::Ty<'tcx> {
             TyKind::Ref(_, ty, mut_ty) => {
                 Typ::Ref(Box::new(ty.to_mmir(tcx, def_id)), mut_ty.to_mmir(tcx, def_id))
             },
+            TyKind::Adt(adt, args) => {
+                Typ::Adt(
+                    def_path(tcx, adt.did()),
+                    adt.adt_kind().to_mmir(tcx, def_id),
+                    args.to_mmir(tcx, def_id),
+                )
+            },
+            TyKind::FnDef(fn_id, args) =>
+                Typ::FnDef(def_path(tcx, *fn_id), args.to_mmir(tcx, def_id)),
+            TyKind::FnPtr(..) =>
+                Typ::FnPtr(self.fn_sig(tcx).skip_binder().to_mmir(tcx, def_id)),
+            TyKind::Closure(closure_id, args) => {
+                let args = args.as_closure();
+                Typ::Closure(
+                    def_path(tcx, *closure_id),
+                    args.kind().to_mmir(tcx, def_id),
+                    upvars(tcx, def_id, *closure_id, args.upvar_tys()),
+                )
+            },
+            TyKind::Coroutine(coroutine_id, args) => {
+                let args = args.as_coroutine();
+                Typ::Coroutine(
+                    def_path(tcx, *coroutine_id),
+                    upvars(tcx, def_id, *coroutine_id, args.upvar_tys()),
+                    mir_types::CoroutineSig {
+                        resume_ty : Box::new(args.resume_ty().to_mmir(tcx, def_id)),
+                        yield_ty : Box::new(args.yield_ty().to_mmir(tcx, def_id)),
+                        return_ty : Box::new(args.return_ty().to_mmir(tcx, def_id)),
+                    },
+                )
+            },
+            TyKind::CoroutineClosure(closure_id, args) => {
+                let args = args.as_coroutine_closure();
+                Typ::CoroutineClosure(
+                    def_path(tcx, *closure_id),
+                    args.kind().to_mmir(tcx, def_id),
+                    upvars(tcx, def_id, *closure_id, args.upvar_tys()),
+                )
+            },
+            TyKind::Tuple(tys) =>
+                Typ::Tuple(tys.iter().map(|ty| ty.to_mmir(tcx, def_id)).collect()),
+            TyKind::Never => Typ::Never,
+            TyKind::Param(param) => Typ::Param(param.index, param.name.to_string()),
+            TyKind::Foreign(foreign_id) => Typ::Foreign(def_path(tcx, *foreign_id)),
+            TyKind::Pat(ty, pat) =>
+                Typ::Pat(Box::new(ty.to_mmir(tcx, def_id)), pat.to_mmir(tcx, def_id)),
+            TyKind::UnsafeBinder(inner) =>
+                Typ::UnsafeBinder(Box::new(inner.skip_binder().to_mmir(tcx, def_id))),
+            TyKind::Alias(kind, alias) =>
+                Typ::Alias(
+                    kind.to_mmir(tcx, def_id),
+                    def_path(tcx, alias.def_id),
+                    alias.args.to_mmir(tcx, def_id),
+                ),
+            TyKind::Dynamic(preds, region, ..) => {
+                let principal = preds.principal().map(|trait_ref| {
+                    let trait_ref = trait_ref.skip_binder();
+                    mir_types::TraitRef {
+                        path : def_path(tcx, trait_ref.def_id),
+                        args : trait_ref.args.to_mmir(tcx, def_id),
+                    }
+                });
+                Typ::Dynamic(mir_types::DynBound {
+                    principal,
+                    auto_traits : preds.auto_traits().map(|did| def_path(tcx, did)).collect(),
+                    region : region.to_string(),
+                })
+            },
             _ => mir_types::Typ::Unknown,
         }
     }
 }
 
+impl<'tcx> Coherce<'tcx> for ty::Pattern<'tcx> {
+    type T = mir_types::TyPattern;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::PatternKind;
+        use mir_types::TyPattern;
+        match **self {
+            PatternKind::Range { start, end } => TyPattern::Range(
+                Box::new(start.to_mmir(tcx, def_id)),
+                Box::new(end.to_mmir(tcx, def_id)),
+            ),
+            PatternKind::Or(pats) =>
+                TyPattern::Or(pats.iter().map(|pat| pat.to_mmir(tcx, def_id)).collect()),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::AliasTyKind {
+    type T = mir_types::AliasKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::AliasTyKind;
+        use mir_types::AliasKind;
+        match self {
+            AliasTyKind::Projection => AliasKind::Projection,
+            AliasTyKind::Inherent => AliasKind::Inherent,
+            AliasTyKind::Opaque => AliasKind::Opaque,
+            AliasTyKind::Free => AliasKind::Free,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::ClosureKind {
+    type T = mir_types::ClosureKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::ClosureKind as MirClosureKind;
+        use mir_types::ClosureKind;
+        match self {
+            MirClosureKind::Fn => ClosureKind::Fn,
+            MirClosureKind::FnMut => ClosureKind::FnMut,
+            MirClosureKind::FnOnce => ClosureKind::FnOnce,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::UpvarCapture {
+    type T = mir_types::CaptureKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::UpvarCapture;
+        use mir_types::CaptureKind;
+        match self {
+            UpvarCapture::ByValue => CaptureKind::ByValue,
+            UpvarCapture::ByUse => CaptureKind::ByUse,
+            UpvarCapture::ByRef(ty::BorrowKind::Immutable) => CaptureKind::ByRef,
+            UpvarCapture::ByRef(ty::BorrowKind::UniqueImmutable) => CaptureKind::ByUniqueRef,
+            UpvarCapture::ByRef(ty::BorrowKind::Mutable) => CaptureKind::ByMutRef,
+        }
+    }
+}
+
+// Upvars of a closure-like type. How each one is captured is only known
+// for closures defined in the current crate.
+fn upvars<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    def_id : def_id::DefId,
+    closure_id : def_id::DefId,
+    upvar_tys : &'tcx ty::List<ty::Ty<'tcx>>,
+) -> Vec<mir_types::Upvar> {
+    let captures = closure_id.as_local().map(|local| tcx.closure_captures(local));
+    upvar_tys
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let captured = captures.and_then(|captures| captures.get(i));
+            mir_types::Upvar {
+                name : captured.map(|place| place.to_string(tcx)).unwrap_or_default(),
+                typ : Box::new(ty.to_mmir(tcx, def_id)),
+                capture : captured
+                    .map(|place| place.info.capture_kind.to_mmir(tcx, def_id))
+                    .unwrap_or(mir_types::CaptureKind::Unknown),
+            }
+        })
+        .collect()
+}
+
+impl<'tcx> Coherce<'tcx> for ty::FnSig<'tcx> {
+    type T = mir_types::FnSig;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        mir_types::FnSig {
+            inputs : self.inputs()
+                .iter()
+                .map(|ty| ty.to_mmir(tcx, def_id))
+                .collect(),
+            output : Box::new(self.output().to_mmir(tcx, def_id)),
+            abi : self.abi.as_str().to_string(),
+            safety : self.safety.to_mmir(tcx, def_id),
+            c_variadic : self.c_variadic,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::AdtKind {
+    type T = mir_types::AdtKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::AdtKind as MirAdtKind;
+        use mir_types::AdtKind;
+        match self {
+            MirAdtKind::Struct => AdtKind::Struct,
+            MirAdtKind::Enum => AdtKind::Enum,
+            MirAdtKind::Union => AdtKind::Union,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::FieldDef {
+    type T = mir_types::FieldDef;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        mir_types::FieldDef {
+            name : self.name.to_string(),
+            typ : Box::new(tcx.type_of(self.did).instantiate_identity().to_mmir(tcx, def_id)),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::AdtDef<'tcx> {
+    type T = mir_types::AdtDef;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        let variants = self.variants()
+            .iter_enumerated()
+            .map(|(idx, variant)| mir_types::VariantDef {
+                name : variant.name.to_string(),
+                discr : self.is_enum().then(|| {
+                    format!("{:#x}", self.discriminant_for_variant(tcx, idx).val)
+                }),
+                fields : variant.fields
+                    .iter()
+                    .map(|field| field.to_mmir(tcx, def_id))
+                    .collect(),
+            })
+            .collect();
+        let repr = self.repr();
+        mir_types::AdtDef {
+            path : def_path(tcx, self.did()),
+            kind : self.adt_kind().to_mmir(tcx, def_id),
+            variants,
+            repr : mir_types::ReprOptions {
+                c : repr.c(),
+                transparent : repr.transparent(),
+                simd : repr.simd(),
+                linear : repr.linear(),
+                int : repr.int.map(|int| Box::new(int.to_ty(tcx).to_mmir(tcx, def_id))),
+                align : repr.align.map(|align| align.bytes() as u32),
+                pack : repr.pack.map(|pack| pack.bytes() as u32),
+            },
+        }
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for ty::Const<'tcx> {
     type T = mir_types::Const;
 
@@ -282,6 +856,10 @@ impl<'tcx> Coherce<'tcx> for ty::Const<'tcx> {
         match self.kind() {
             ConstKind::Param(param) =>
                 Const::Param(param.index),
+            ConstKind::Value(value) => Const::Val(
+                tcx.valtree_to_const_val(value).to_mmir(tcx, def_id),
+                Box::new(value.ty.to_mmir(tcx, def_id)),
+            ),
             ConstKind::Expr(exp) => {
                 let mut args : Vec<mir_types::Arg> = Vec::new();
                 for i in 0..exp.args().len() {
@@ -299,6 +877,39 @@ impl<'tcx> Coherce<'tcx> for ty::Const<'tcx> {
     }
 }
 
+impl<'tcx> Coherce<'tcx> for ty::GenericArg<'tcx> {
+    type T = mir_types::GenericArg;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir_types::GenericArg;
+        if let Some(ty) = self.as_type() {
+            GenericArg::Type(Box::new(ty.to_mmir(tcx, def_id)))
+        } else if let Some(cst) = self.as_const() {
+            GenericArg::Const(Box::new(cst.to_mmir(tcx, def_id)))
+        } else {
+            GenericArg::Lifetime
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::GenericArgsRef<'tcx> {
+    type T = Vec<mir_types::GenericArg>;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        self.iter()
+            .map(|arg| arg.to_mmir(tcx, def_id))
+            .collect()
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for mir::ConstValue<'tcx> {
     type T = mir_types::ConstVal;
 
@@ -313,7 +924,10 @@ impl<'tcx> Coherce<'tcx> for mir::ConstValue<'tcx> {
         match self {
             ConstValue::Scalar(scalar) => {
                 match scalar {
-                    Scalar::Int(int) => ConstVal::ScalarInt(int.to_u32()),
+                    Scalar::Int(int) => ConstVal::ScalarInt(
+                        format!("{:#x}", int.to_bits_unchecked()),
+                        int.size().bytes() as u8,
+                    ),
                     Scalar::Ptr(ptr, size) => {
                         let (prov, off) = ptr.into_raw_parts();
                         let mir::interpret::AllocId(id) = prov.alloc_id();
@@ -326,14 +940,68 @@ impl<'tcx> Coherce<'tcx> for mir::ConstValue<'tcx> {
                 }
             }
             ConstValue::ZeroSized => ConstVal::ZeroSized,
-            ConstValue::Slice { data, meta } =>
-                ConstVal::Slice(*meta as u32, data.inner().mutability.to_mmir(tcx, def_id)),
+            ConstValue::Slice { data, meta } => {
+                let AllocId(id) = slice_alloc_id(tcx, *data);
+                ConstVal::Slice(
+                    id.get() as u32,
+                    *meta as u32,
+                    data.inner().mutability.to_mmir(tcx, def_id),
+                )
+            },
             ConstValue::Indirect { alloc_id : AllocId(id), offset } =>
                 ConstVal::Indirect(id.get() as u32, offset.bytes() as u32),
         }
     }
 }
 
+impl<'tcx> Coherce<'tcx> for mir::interpret::Allocation {
+    type T = mir_types::Allocation;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        let relocations = self.provenance()
+            .ptrs()
+            .iter()
+            .map(|(offset, prov)| {
+                let mir::interpret::AllocId(id) = prov.alloc_id();
+                mir_types::Relocation {
+                    offset : offset.bytes() as u32,
+                    target : id.get() as u32,
+                }
+            })
+            .collect();
+        mir_types::Allocation {
+            bytes : self.inspect_with_uninit_and_ptr_outside_interpreter(0..self.len()).to_vec(),
+            align : self.align.bytes() as u32,
+            mutability : self.mutability.to_mmir(tcx, def_id),
+            relocations,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::interpret::GlobalAlloc<'tcx> {
+    type T = mir_types::GlobalAlloc;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::interpret::GlobalAlloc as MirGlobalAlloc;
+        use mir_types::GlobalAlloc;
+        match self {
+            MirGlobalAlloc::Memory(alloc) => GlobalAlloc::Memory(alloc.inner().to_mmir(tcx, def_id)),
+            MirGlobalAlloc::Function { instance } =>
+                GlobalAlloc::Function(instance.to_mmir(tcx, def_id)),
+            MirGlobalAlloc::VTable(ty, ..) => GlobalAlloc::VTable(Box::new(ty.to_mmir(tcx, def_id))),
+            MirGlobalAlloc::Static(static_id) => GlobalAlloc::Static(def_path(tcx, *static_id)),
+        }
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for mir::Mutability {
     type T = mir_types::Mutability;
 
@@ -351,6 +1019,23 @@ impl<'tcx> Coherce<'tcx> for mir::Mutability {
     }
 }
 
+impl<'tcx> Coherce<'tcx> for rustc_hir::Safety {
+    type T = mir_types::Safety;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use rustc_hir::Safety as HirSafety;
+        use mir_types::Safety;
+        match self {
+            HirSafety::Safe => Safety::Safe,
+            HirSafety::Unsafe => Safety::Unsafe,
+        }
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for mir::BinOp {
     type T = mir_types::BinOp;
 
@@ -527,8 +1212,123 @@ impl<'tcx> Coherce<'tcx> for mir::BorrowKind {
         use mir_types::BorrowKind;
         match self {
             MirBorrowKind::Shared => BorrowKind::Shared,
-            MirBorrowKind::Fake(_) => BorrowKind::Fake,
-            MirBorrowKind::Mut { kind : _ } => BorrowKind::Mut,
+            MirBorrowKind::Fake(kind) => BorrowKind::Fake(match kind {
+                mir::FakeBorrowKind::Shallow => mir_types::FakeBorrowKind::Shallow,
+                mir::FakeBorrowKind::Deep => mir_types::FakeBorrowKind::Deep,
+            }),
+            MirBorrowKind::Mut { kind } => BorrowKind::Mut(match kind {
+                mir::MutBorrowKind::Default => mir_types::MutBorrowKind::Default,
+                mir::MutBorrowKind::TwoPhaseBorrow => mir_types::MutBorrowKind::TwoPhaseBorrow,
+                mir::MutBorrowKind::ClosureCapture => mir_types::MutBorrowKind::ClosureCapture,
+            }),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::Region<'tcx> {
+    type T = mir_types::Region;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir_types::Region;
+        // MIR is built with erased regions, the region variables of borrowck
+        // only exist in its own copy of the body.
+        match self.kind() {
+            ty::RegionKind::ReStatic => Region::Static,
+            ty::RegionKind::ReErased => Region::Erased,
+            _ => Region::Other(self.to_string()),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::AggregateKind<'tcx> {
+    type T = mir_types::AggregateKind;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::AggregateKind as MirAggregateKind;
+        use mir_types::AggregateKind;
+        match self {
+            MirAggregateKind::Array(ty) =>
+                AggregateKind::Array(Box::new(ty.to_mmir(tcx, def_id))),
+            MirAggregateKind::Tuple => AggregateKind::Tuple,
+            MirAggregateKind::Adt(adt_id, variant, args, _, active_field) =>
+                AggregateKind::Adt(
+                    def_path(tcx, *adt_id),
+                    variant.as_u32(),
+                    args.to_mmir(tcx, def_id),
+                    active_field.map(|f| f.as_u32()),
+                ),
+            MirAggregateKind::Closure(closure_id, args) =>
+                AggregateKind::Closure(def_path(tcx, *closure_id), args.to_mmir(tcx, def_id)),
+            MirAggregateKind::Coroutine(coroutine_id, args) =>
+                AggregateKind::Coroutine(def_path(tcx, *coroutine_id), args.to_mmir(tcx, def_id)),
+            MirAggregateKind::CoroutineClosure(closure_id, args) =>
+                AggregateKind::CoroutineClosure(
+                    def_path(tcx, *closure_id),
+                    args.to_mmir(tcx, def_id),
+                ),
+            MirAggregateKind::RawPtr(ty, mut_ty) =>
+                AggregateKind::RawPtr(Box::new(ty.to_mmir(tcx, def_id)), mut_ty.to_mmir(tcx, def_id)),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::adjustment::PointerCoercion {
+    type T = mir_types::PointerCoercion;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::adjustment::PointerCoercion as MirPointerCoercion;
+        use mir_types::PointerCoercion;
+        match self {
+            MirPointerCoercion::ReifyFnPointer => PointerCoercion::ReifyFnPointer,
+            MirPointerCoercion::UnsafeFnPointer => PointerCoercion::UnsafeFnPointer,
+            MirPointerCoercion::ClosureFnPointer(safety) =>
+                PointerCoercion::ClosureFnPointer(safety.to_mmir(tcx, def_id)),
+            MirPointerCoercion::MutToConstPointer => PointerCoercion::MutToConstPointer,
+            MirPointerCoercion::ArrayToPointer => PointerCoercion::ArrayToPointer,
+            MirPointerCoercion::Unsize => PointerCoercion::Unsize,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::CastKind {
+    type T = mir_types::CastKind;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::CastKind as MirCastKind;
+        use mir_types::{CastKind, CoercionSource};
+        match self {
+            MirCastKind::PointerExposeProvenance => CastKind::PointerExposeProvenance,
+            MirCastKind::PointerWithExposedProvenance => CastKind::PointerWithExposedProvenance,
+            MirCastKind::PointerCoercion(coercion, source) => {
+                let source = match source {
+                    mir::CoercionSource::AsCast => CoercionSource::AsCast,
+                    mir::CoercionSource::Implicit => CoercionSource::Implicit,
+                };
+                CastKind::PointerCoercion(coercion.to_mmir(tcx, def_id), source)
+            },
+            MirCastKind::IntToInt => CastKind::IntToInt,
+            MirCastKind::FloatToInt => CastKind::FloatToInt,
+            MirCastKind::FloatToFloat => CastKind::FloatToFloat,
+            MirCastKind::IntToFloat => CastKind::IntToFloat,
+            MirCastKind::PtrToPtr => CastKind::PtrToPtr,
+            MirCastKind::FnPtrToPtr => CastKind::FnPtrToPtr,
+            MirCastKind::Transmute => CastKind::Transmute,
         }
     }
 }
@@ -547,8 +1347,12 @@ impl<'tcx> Coherce<'tcx> for mir::Rvalue<'tcx> {
             MirRvalue::Use(op) => Rvalue::Use(op.to_mmir(tcx, def_id)),
             MirRvalue::Repeat(op, cst) =>
                 Rvalue::Repeat(op.to_mmir(tcx, def_id), Box::new(cst.to_mmir(tcx, def_id))),
-            MirRvalue::Ref(_, borrow_kind, place) =>
-                Rvalue::Ref(borrow_kind.to_mmir(tcx, def_id), place.to_mmir(tcx, def_id)),
+            MirRvalue::Ref(region, borrow_kind, place) =>
+                Rvalue::Ref(
+                    region.to_mmir(tcx, def_id),
+                    borrow_kind.to_mmir(tcx, def_id),
+                    place.to_mmir(tcx, def_id),
+                ),
             MirRvalue::RawPtr(kind, place) => {
                 let place_mmir = place.to_mmir(tcx, def_id);
                 match kind {
@@ -584,6 +1388,17 @@ impl<'tcx> Coherce<'tcx> for mir::Rvalue<'tcx> {
                     op.to_mmir(tcx, def_id),
                     Box::new(ty.to_mmir(tcx, def_id)),
                 ),
+            MirRvalue::Aggregate(kind, ops) =>
+                Rvalue::Aggregate(
+                    kind.to_mmir(tcx, def_id),
+                    ops.to_mmir(tcx, def_id),
+                ),
+            MirRvalue::Cast(kind, op, ty) =>
+                Rvalue::Cast(
+                    kind.to_mmir(tcx, def_id),
+                    op.to_mmir(tcx, def_id),
+                    Box::new(ty.to_mmir(tcx, def_id)),
+                ),
             _ => Rvalue::Unknown,
         }
     }
@@ -598,12 +1413,46 @@ impl<'tcx> Coherce<'tcx> for mir::SwitchTargets {
         _def_id: def_id::DefId,
     ) -> Self::T {
         let mut targets : Vec<u32> = Vec::new();
-        let mut values : Vec<u32> = Vec::new();
+        let mut values : Vec<String> = Vec::new();
         for (val, targ) in self.iter() {
             targets.push(targ.as_u32());
-            values.push(val as u32);
+            values.push(format!("{:#x}", val));
+        }
+        mir_types::Targets { targets, values, otherwise : self.otherwise().as_u32() }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for rustc_hir::CoroutineKind {
+    type T = mir_types::CoroutineKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use rustc_hir::{CoroutineDesugaring as MirDesugaring, CoroutineSource as MirSource};
+        use mir_types::{CoroutineDesugaring, CoroutineKind, CoroutineSource, Movability};
+        match self {
+            rustc_hir::CoroutineKind::Desugared(desugaring, source) => {
+                let desugaring = match desugaring {
+                    MirDesugaring::Async => CoroutineDesugaring::Async,
+                    MirDesugaring::Gen => CoroutineDesugaring::Gen,
+                    MirDesugaring::AsyncGen => CoroutineDesugaring::AsyncGen,
+                };
+                let source = match source {
+                    MirSource::Block => CoroutineSource::Block,
+                    MirSource::Closure => CoroutineSource::Closure,
+                    MirSource::Fn => CoroutineSource::Fn,
+                };
+                CoroutineKind::Desugared(desugaring, source)
+            }
+            rustc_hir::CoroutineKind::Coroutine(movability) => {
+                CoroutineKind::Coroutine(match movability {
+                    rustc_ast::Movability::Static => Movability::Static,
+                    rustc_ast::Movability::Movable => Movability::Movable,
+                })
+            }
         }
-        mir_types::Targets { targets, values }
     }
 }
 
@@ -641,11 +1490,101 @@ impl<'tcx> Coherce<'tcx> for mir::AssertMessage<'tcx> {
                 ),
             MirAssertMessage::NullPointerDereference =>
                 AssertMessage::NullPointerDereference,
-            _ => AssertMessage::Unknown,
+            MirAssertMessage::ResumedAfterReturn(kind) =>
+                AssertMessage::ResumedAfterReturn(kind.to_mmir(tcx, def_id)),
+            MirAssertMessage::ResumedAfterPanic(kind) =>
+                AssertMessage::ResumedAfterPanic(kind.to_mmir(tcx, def_id)),
+            MirAssertMessage::ResumedAfterDrop(kind) =>
+                AssertMessage::ResumedAfterDrop(kind.to_mmir(tcx, def_id)),
+            MirAssertMessage::InvalidEnumConstruction(op) =>
+                AssertMessage::InvalidEnumConstruction(op.to_mmir(tcx, def_id)),
         }
     }
 }
 
+impl<'tcx> Coherce<'tcx> for ty::InstanceKind<'tcx> {
+    type T = mir_types::InstanceKind;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::InstanceKind as MirInstanceKind;
+        use mir_types::InstanceKind;
+        match self {
+            MirInstanceKind::Item(_) => InstanceKind::Item,
+            MirInstanceKind::Intrinsic(_) => InstanceKind::Intrinsic,
+            MirInstanceKind::VTableShim(_) => InstanceKind::VTableShim,
+            MirInstanceKind::ReifyShim(..) => InstanceKind::ReifyShim,
+            MirInstanceKind::FnPtrShim(_, ty) =>
+                InstanceKind::FnPtrShim(Box::new(ty.to_mmir(tcx, def_id))),
+            MirInstanceKind::Virtual(_, idx) => InstanceKind::Virtual(*idx as u32),
+            MirInstanceKind::ClosureOnceShim { .. } => InstanceKind::ClosureOnceShim,
+            MirInstanceKind::ConstructCoroutineInClosureShim { .. } =>
+                InstanceKind::ConstructCoroutineInClosureShim,
+            MirInstanceKind::ThreadLocalShim(_) => InstanceKind::ThreadLocalShim,
+            MirInstanceKind::DropGlue(_, ty) =>
+                InstanceKind::DropGlue(ty.map(|ty| Box::new(ty.to_mmir(tcx, def_id)))),
+            MirInstanceKind::CloneShim(_, ty) =>
+                InstanceKind::CloneShim(Box::new(ty.to_mmir(tcx, def_id))),
+            MirInstanceKind::FnPtrAddrShim(_, ty) =>
+                InstanceKind::FnPtrAddrShim(Box::new(ty.to_mmir(tcx, def_id))),
+            MirInstanceKind::FutureDropPollShim(_, proxy_ty, impl_ty) => {
+                InstanceKind::FutureDropPollShim(
+                    Box::new(proxy_ty.to_mmir(tcx, def_id)),
+                    Box::new(impl_ty.to_mmir(tcx, def_id)),
+                )
+            }
+            MirInstanceKind::AsyncDropGlueCtorShim(_, ty) =>
+                InstanceKind::AsyncDropGlueCtorShim(Box::new(ty.to_mmir(tcx, def_id))),
+            MirInstanceKind::AsyncDropGlue(_, ty) =>
+                InstanceKind::AsyncDropGlue(Box::new(ty.to_mmir(tcx, def_id))),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::Instance<'tcx> {
+    type T = mir_types::Instance;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        mir_types::Instance {
+            def_path : def_path(tcx, self.def_id()),
+            kind : self.def.to_mmir(tcx, def_id),
+            args : self.args.to_mmir(tcx, def_id),
+        }
+    }
+}
+
+// Resolves the callee of a `Call` terminator the way codegen would, as seen
+// from the body that contains the call. Bodies are only lowered once the
+// crate is analysed, whatever their phase (see `EARLY_MIR` in lib.rs), so
+// resolution forces no check early. Early phases still hold unnormalized
+// projections in the arguments, which resolution expects normalized.
+fn call_target<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    def_id : def_id::DefId,
+    func : &mir::Operand<'tcx>,
+) -> mir_types::CallTarget {
+    use mir_types::CallTarget;
+    let Some((fn_id, args)) = func.const_fn_def() else {
+        return CallTarget::Indirect;
+    };
+    let typing_env = ty::TypingEnv::post_analysis(tcx, def_id);
+    let Ok(args) = tcx.try_normalize_erasing_regions(typing_env, args) else {
+        return CallTarget::Failed;
+    };
+    match ty::Instance::try_resolve(tcx, typing_env, fn_id, args) {
+        Ok(Some(instance)) => CallTarget::Resolved(instance.to_mmir(tcx, def_id)),
+        Ok(None) => CallTarget::TooGeneric,
+        Err(_) => CallTarget::Failed,
+    }
+}
+
 impl<'tcx> Coherce<'tcx> for mir::TerminatorKind<'tcx> {
     type T = mir_types::StatementKind;
 
@@ -690,7 +1629,8 @@ impl<'tcx> Coherce<'tcx> for mir::TerminatorKind<'tcx> {
                     dest : destination.to_mmir(tcx, def_id),
                     target : target.map(|x| x.as_u32()),
                     unwind : unwind.to_mmir(tcx, def_id),
-                    span : mir_types::Span::Span(fn_span.lo().0, fn_span.hi().0),
+                    span : fn_span.to_mmir(tcx, def_id),
+                    resolved : call_target(tcx, def_id, func),
                 })
             },
             MirTerminatorKind::Assert { cond, expected, msg, target, unwind } => {
@@ -702,7 +1642,133 @@ impl<'tcx> Coherce<'tcx> for mir::TerminatorKind<'tcx> {
                     unwind : unwind.to_mmir(tcx, def_id),
                 })
             }
-            _ => StatementKind::Unknown,
+            MirTerminatorKind::Yield { value, resume, resume_arg, drop } => {
+                StatementKind::Yield(mir_types::YieldInfo {
+                    value : value.to_mmir(tcx, def_id),
+                    resume : resume.as_u32(),
+                    resume_arg : resume_arg.to_mmir(tcx, def_id),
+                    drop : drop.map(|x| x.as_u32()),
+                })
+            }
+            MirTerminatorKind::FalseEdge { real_target, imaginary_target } => {
+                StatementKind::FalseEdge(real_target.as_u32(), imaginary_target.as_u32())
+            }
+            MirTerminatorKind::FalseUnwind { real_target, unwind } => {
+                StatementKind::FalseUnwind(real_target.as_u32(), unwind.to_mmir(tcx, def_id))
+            }
+            MirTerminatorKind::InlineAsm { template, operands, options, targets, unwind, .. } => {
+                StatementKind::InlineAsm(mir_types::InlineAsmInfo {
+                    template : rustc_ast::InlineAsmTemplatePiece::to_string(template),
+                    operands : operands.iter().map(|x| x.to_mmir(tcx, def_id)).collect(),
+                    options : options
+                        .human_readable_names()
+                        .into_iter()
+                        .map(String::from)
+                        .collect(),
+                    targets : targets.iter().map(|x| x.as_u32()).collect(),
+                    unwind : unwind.to_mmir(tcx, def_id),
+                })
+            }
+            MirTerminatorKind::TailCall { func, args, .. } => {
+                StatementKind::TailCall(
+                    func.to_mmir(tcx, def_id),
+                    args.iter().map(|x| x.node.to_mmir(tcx, def_id)).collect(),
+                )
+            }
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::FakeReadCause {
+    type T = mir_types::FakeReadCause;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::FakeReadCause as MirFakeReadCause;
+        use mir_types::FakeReadCause;
+        match self {
+            MirFakeReadCause::ForMatchGuard => FakeReadCause::ForMatchGuard,
+            MirFakeReadCause::ForMatchedPlace(closure) => {
+                FakeReadCause::ForMatchedPlace(closure.map(|c| def_path(tcx, c.to_def_id())))
+            }
+            MirFakeReadCause::ForGuardBinding => FakeReadCause::ForGuardBinding,
+            MirFakeReadCause::ForLet(closure) => {
+                FakeReadCause::ForLet(closure.map(|c| def_path(tcx, c.to_def_id())))
+            }
+            MirFakeReadCause::ForIndex => FakeReadCause::ForIndex,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for ty::Variance {
+    type T = mir_types::Variance;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use ty::Variance as MirVariance;
+        use mir_types::Variance;
+        match self {
+            MirVariance::Covariant => Variance::Covariant,
+            MirVariance::Invariant => Variance::Invariant,
+            MirVariance::Contravariant => Variance::Contravariant,
+            MirVariance::Bivariant => Variance::Bivariant,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::coverage::CoverageKind {
+    type T = mir_types::CoverageKind;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::coverage::CoverageKind as MirCoverageKind;
+        use mir_types::CoverageKind;
+        match self {
+            MirCoverageKind::SpanMarker => CoverageKind::SpanMarker,
+            MirCoverageKind::BlockMarker { id } => CoverageKind::BlockMarker(id.as_u32()),
+            MirCoverageKind::VirtualCounter { bcb } => CoverageKind::VirtualCounter(bcb.as_u32()),
+            MirCoverageKind::CondBitmapUpdate { index, decision_depth } =>
+                CoverageKind::CondBitmapUpdate(*index, *decision_depth as u32),
+            MirCoverageKind::TestVectorBitmapUpdate { bitmap_idx, decision_depth } =>
+                CoverageKind::TestVectorBitmapUpdate(*bitmap_idx, *decision_depth as u32),
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::BackwardIncompatibleDropReason {
+    type T = mir_types::DropHintReason;
+
+    fn to_mmir(
+        &self,
+        _tcx: ty::TyCtxt<'tcx>,
+        _def_id: def_id::DefId,
+    ) -> Self::T {
+        match self {
+            mir::BackwardIncompatibleDropReason::Edition2024 => mir_types::DropHintReason::Edition2024,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::UserTypeProjection {
+    type T = mir_types::UserTypeProjection;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        mir_types::UserTypeProjection {
+            base : self.base.as_u32(),
+            projs : self.projs.iter().map(|p| p.to_mmir(tcx, def_id)).collect(),
         }
     }
 }
@@ -747,7 +1813,27 @@ impl<'tcx> Coherce<'tcx> for mir::StatementKind<'tcx> {
                 ),
             MirStatementKind::Intrinsic(intrinsic) =>
                 StatementKind::Intrinsic(*intrinsic.to_mmir(tcx, def_id)),
-            _ => StatementKind::Unknown,
+            MirStatementKind::FakeRead(args) => {
+                let (cause, place) = &**args;
+                StatementKind::FakeRead(
+                    cause.to_mmir(tcx, def_id),
+                    place.to_mmir(tcx, def_id),
+                )
+            }
+            MirStatementKind::AscribeUserType(args, variance) => {
+                let (place, proj) = &**args;
+                StatementKind::AscribeUserType(
+                    place.to_mmir(tcx, def_id),
+                    proj.to_mmir(tcx, def_id),
+                    variance.to_mmir(tcx, def_id),
+                )
+            }
+            MirStatementKind::Coverage(kind) => StatementKind::Coverage(kind.to_mmir(tcx, def_id)),
+            MirStatementKind::BackwardIncompatibleDropHint { place, reason } =>
+                StatementKind::BackwardIncompatibleDropHint(
+                    *place.to_mmir(tcx, def_id),
+                    reason.to_mmir(tcx, def_id),
+                ),
         }
     }
 }
@@ -762,8 +1848,49 @@ impl<'tcx> Coherce<'tcx> for mir::Statement<'tcx> {
     ) -> Self::T {
         mir_types::Statement {
             skind: self.kind.to_mmir(tcx, def_id),
-            span: mir_types::Span::Span(self.source_info.span.lo().0, self.source_info.span.hi().0),
+            span: self.source_info.span.to_mmir(tcx, def_id),
             scope: self.source_info.scope.as_u32(),
+            // Set by `Body::to_mmir`, see `unsafety::UnsafetyMap::annotate`.
+            safety: mir_types::SafetyContext::Safe,
+        }
+    }
+}
+
+impl<'tcx> Coherce<'tcx> for mir::InlineAsmOperand<'tcx> {
+    type T = mir_types::AsmOperand;
+
+    fn to_mmir(
+        &self,
+        tcx: ty::TyCtxt<'tcx>,
+        def_id: def_id::DefId,
+    ) -> Self::T {
+        use mir::InlineAsmOperand as MirAsmOperand;
+        use mir_types::AsmOperand;
+        match self {
+            MirAsmOperand::In { reg, value } => {
+                AsmOperand::In(reg.to_string(), value.to_mmir(tcx, def_id))
+            }
+            MirAsmOperand::Out { reg, late, place } => {
+                AsmOperand::Out(
+                    reg.to_string(),
+                    *late,
+                    place.map(|p| p.to_mmir(tcx, def_id)),
+                )
+            }
+            MirAsmOperand::InOut { reg, late, in_value, out_place } => {
+                AsmOperand::InOut(
+                    reg.to_string(),
+                    *late,
+                    in_value.to_mmir(tcx, def_id),
+                    out_place.map(|p| p.to_mmir(tcx, def_id)),
+                )
+            }
+            MirAsmOperand::Const { value } => AsmOperand::Const(value.to_mmir(tcx, def_id)),
+            MirAsmOperand::SymFn { value } => AsmOperand::SymFn(value.to_mmir(tcx, def_id)),
+            MirAsmOperand::SymStatic { def_id : did } => {
+                AsmOperand::SymStatic(def_path(tcx, *did))
+            }
+            MirAsmOperand::Label { target_index } => AsmOperand::Label(*target_index as u32),
         }
     }
 }
@@ -799,8 +1926,10 @@ impl<'tcx> Coherce<'tcx> for mir::Terminator<'tcx> {
         use mir_types::Statement;
         Statement {
             skind : self.kind.to_mmir(tcx, def_id),
-            span : mir_types::Span::Span(self.source_info.span.lo().0, self.source_info.span.hi().0),
+            span : self.source_info.span.to_mmir(tcx, def_id),
             scope: self.source_info.scope.as_u32(),
+            // Set by `Body::to_mmir`, see `unsafety::UnsafetyMap::annotate`.
+            safety: mir_types::SafetyContext::Safe,
         }
     }
 }
@@ -848,12 +1977,36 @@ impl<'tcx> Coherce<'tcx> for mir::LocalInfo<'tcx> {
 
     fn to_mmir(
         &self,
-        _tcx : ty::TyCtxt<'tcx>,
-        _def_id : def_id::DefId,
+        tcx : ty::TyCtxt<'tcx>,
+        def_id : def_id::DefId,
     ) -> Self::T {
         use mir::LocalInfo as MirLocalInfo;
         use mir_types::LocalInfo;
         match self {
+            MirLocalInfo::User(mir::BindingForm::Var(var)) => {
+                let rustc_ast::BindingMode(by_ref, mutability) = var.binding_mode;
+                LocalInfo::User(mir_types::VarBinding {
+                    by_ref : match by_ref {
+                        rustc_ast::ByRef::Yes(m) => Some(m.to_mmir(tcx, def_id)),
+                        rustc_ast::ByRef::No => None,
+                    },
+                    mutability : mutability.to_mmir(tcx, def_id),
+                    opt_ty_info : var.opt_ty_info.map(|s| s.to_mmir(tcx, def_id)),
+                    pat_span : var.pat_span.to_mmir(tcx, def_id),
+                })
+            }
+            MirLocalInfo::User(mir::BindingForm::ImplicitSelf(kind)) => {
+                use rustc_hir::ImplicitSelfKind as HirSelfKind;
+                use mir_types::ImplicitSelfKind;
+                LocalInfo::ImplicitSelf(match kind {
+                    HirSelfKind::Imm => ImplicitSelfKind::Imm,
+                    HirSelfKind::Mut => ImplicitSelfKind::Mut,
+                    HirSelfKind::RefImm => ImplicitSelfKind::RefImm,
+                    HirSelfKind::RefMut => ImplicitSelfKind::RefMut,
+                    HirSelfKind::None => ImplicitSelfKind::NoSelf,
+                })
+            }
+            MirLocalInfo::User(mir::BindingForm::RefForGuard(..)) => LocalInfo::RefForGuard,
             MirLocalInfo::FakeBorrow => LocalInfo::FakeBorrow,
             MirLocalInfo::DerefTemp => LocalInfo::DerefTemp,
             MirLocalInfo::Boring => LocalInfo::Boring,
@@ -862,7 +2015,8 @@ impl<'tcx> Coherce<'tcx> for mir::LocalInfo<'tcx> {
             MirLocalInfo::StaticRef { def_id, .. } =>
                 LocalInfo::StaticRef(def_id.index.as_u32()),
             MirLocalInfo::AggregateTemp => LocalInfo::AggregateTemp,
-            _ => LocalInfo::Unknown,
+            MirLocalInfo::BlockTailTemp(_) | MirLocalInfo::IfThenRescopeTemp { .. } =>
+                LocalInfo::Unknown,
         }
     }
 }
@@ -880,6 +2034,13 @@ impl<'tcx> Coherce<'tcx> for mir::LocalDecl<'tcx> {
             local : self.local_info().to_mmir(tcx, def_id),
             typ: Box::new(self.ty.to_mmir(tcx, def_id)),
             r#mut: self.mutability.to_mmir(tcx, def_id),
+            span : self.source_info.span.to_mmir(tcx, def_id),
+            user_ty : self
+                .user_ty
+                .iter()
+                .flat_map(|projs| projs.contents.iter())
+                .map(|proj| proj.to_mmir(tcx, def_id))
+                .collect(),
         }
     }
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 #![feature(rustc_private)]
 
+extern crate rustc_ast;
+extern crate rustc_data_structures;
 extern crate rustc_driver;
+extern crate rustc_hir;
 extern crate rustc_interface;
 extern crate rustc_middle;
 extern crate rustc_span;
@@ -8,45 +11,327 @@ extern crate rustc_index;
 
 pub mod mir_types;
 pub mod coherce;
+pub mod tables;
+pub mod unsafety;
+pub mod workspace;
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use rustc_data_structures::steal::Steal;
 use rustc_driver::{Callbacks, run_compiler};
-use rustc_middle::ty;
+use rustc_index::IndexVec;
+use rustc_middle::mir::visit::Visitor;
+use rustc_middle::{mir, ty};
+use rustc_span::def_id::LocalDefId;
 use coherce::Coherce;
+use mir_types::MirPhase;
+
+type Promoted<'tcx> = IndexVec<mir::Promoted, mir::Body<'tcx>>;
+
+// Copy of the MIR of a body at an early phase, with its promoted constants.
+struct EarlyMir {
+    body : &'static mir::Body<'static>,
+    promoted : &'static Promoted<'static>,
+}
+
+thread_local! {
+    // The MIR of the phases before borrowck (and the drop elaborated MIR of
+    // constants, for const evaluation) is stolen by the analysis. The query
+    // overrides installed by `MirHook` keep a copy of it in the arena of the
+    // compiler as it is built, so the extraction still runs after the
+    // analysis, on a checked crate. The front end is single threaded, so the
+    // queries run on the thread of the callbacks.
+    static EARLY_MIR : RefCell<HashMap<LocalDefId, EarlyMir>> = RefCell::new(HashMap::new());
+}
+
+static DEFAULT_PROVIDERS : OnceLock<rustc_middle::query::Providers> = OnceLock::new();
+
+fn keep_early_mir<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+    body : &mir::Body<'tcx>,
+    promoted : Promoted<'tcx>,
+) {
+    let body : &'tcx mir::Body<'tcx> = tcx.arena.alloc(body.clone());
+    let promoted : &'tcx Promoted<'tcx> = tcx.arena.alloc(promoted);
+    // SAFETY: the copies live as long as `tcx`. They are only read back by
+    // `early_mir`, with the lifetime of `tcx`, and the table is cleared once
+    // extracted (each compiler also runs on a thread of its own).
+    let early = unsafe {
+        EarlyMir { body : std::mem::transmute(body), promoted : std::mem::transmute(promoted) }
+    };
+    EARLY_MIR.with_borrow_mut(|early_mir| early_mir.insert(local_def_id, early));
+}
+
+fn early_mir<'tcx>(
+    _tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+) -> Option<(&'tcx mir::Body<'tcx>, &'tcx Promoted<'tcx>)> {
+    EARLY_MIR.with_borrow(|early_mir| {
+        let early = early_mir.get(&local_def_id)?;
+        // SAFETY: see `keep_early_mir`.
+        unsafe { Some((std::mem::transmute(early.body), std::mem::transmute(early.promoted))) }
+    })
+}
+
+fn mir_built<'tcx>(tcx : ty::TyCtxt<'tcx>, local_def_id : LocalDefId) -> &'tcx Steal<mir::Body<'tcx>> {
+    let body = (DEFAULT_PROVIDERS.get().unwrap().mir_built)(tcx, local_def_id);
+    keep_early_mir(tcx, local_def_id, &body.borrow(), IndexVec::new());
+    body
+}
+
+fn mir_promoted<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+) -> (&'tcx Steal<mir::Body<'tcx>>, &'tcx Steal<Promoted<'tcx>>) {
+    let (body, promoted) = (DEFAULT_PROVIDERS.get().unwrap().mir_promoted)(tcx, local_def_id);
+    keep_early_mir(tcx, local_def_id, &body.borrow(), promoted.borrow().clone());
+    (body, promoted)
+}
+
+fn mir_drops_elaborated_and_const_checked<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+) -> &'tcx Steal<mir::Body<'tcx>> {
+    let body = (DEFAULT_PROVIDERS.get().unwrap().mir_drops_elaborated_and_const_checked)(tcx, local_def_id);
+    keep_early_mir(tcx, local_def_id, &body.borrow(), IndexVec::new());
+    body
+}
+
+// MIR of a body at the given phase, `None` if rustc kept no copy of it.
+fn mir_at_phase<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+    phase : MirPhase,
+) -> Option<Cow<'tcx, mir::Body<'tcx>>> {
+    let def_id = local_def_id.to_def_id();
+    // Bodies the analysis did not need are built now, through the overrides.
+    match phase {
+        MirPhase::Built => {
+            let _ = tcx.mir_built(local_def_id);
+        }
+        MirPhase::Promoted => {
+            let _ = tcx.mir_promoted(local_def_id);
+        }
+        MirPhase::DropsElaborated => {
+            let _ = tcx.mir_drops_elaborated_and_const_checked(local_def_id);
+        }
+        _ => (),
+    }
+    match phase {
+        MirPhase::Built | MirPhase::Promoted | MirPhase::DropsElaborated =>
+            early_mir(tcx, local_def_id).map(|(body, _)| Cow::Borrowed(body)),
+        // `optimized_mir` of functions, `mir_for_ctfe` of constants and
+        // statics, which are never optimized (`optimized_mir` rejects them).
+        MirPhase::Optimized => Some(Cow::Borrowed(tcx.instance_mir(ty::InstanceKind::Item(def_id)))),
+    }
+}
+
+// Promoted constants of a body, which only exist once promotion has run.
+// Past promotion, rustc only keeps them lowered to runtime MIR
+// (`promoted_mir`), which is what the owner refers to from then on.
+fn promoted_at_phase<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    local_def_id : LocalDefId,
+    phase : MirPhase,
+) -> Cow<'tcx, Promoted<'tcx>> {
+    match phase {
+        MirPhase::Built => Cow::Owned(IndexVec::new()),
+        MirPhase::Promoted => early_mir(tcx, local_def_id)
+            .map_or(Cow::Owned(IndexVec::new()), |(_, promoted)| Cow::Borrowed(promoted)),
+        _ => Cow::Borrowed(tcx.promoted_mir(local_def_id)),
+    }
+}
 
 struct MirHook {
+    phase : MirPhase,
+    // Prints the rustc MIR of each body as it is lowered.
+    dump : bool,
     mir_ast : Vec<mir_types::Body>,
+    adt_table : Vec<mir_types::AdtDef>,
+    alloc_table : Vec<mir_types::Alloc>,
+    source_files : Vec<mir_types::SourceFile>,
+}
+
+impl MirHook {
+    fn extract<'tcx>(&mut self, tcx : ty::TyCtxt<'tcx>) {
+        let bodies : Vec<_> = tcx
+            .hir_body_owners()
+            .filter_map(|local_def_id| {
+                let body = mir_at_phase(tcx, local_def_id, self.phase)?;
+                let promoted = promoted_at_phase(tcx, local_def_id, self.phase);
+                Some((local_def_id.to_def_id(), body, promoted))
+            })
+            .collect();
+        let mut adts = tables::AdtCollector::new(tcx);
+        let mut allocs = tables::AllocCollector::new(tcx);
+        for (def_id, mir_body, promoted) in &bodies {
+            if self.dump {
+                println!("Processing MIR for {:?} :\n {:#?}", def_id, mir_body);
+            }
+            for mir_body in std::iter::once(&**mir_body).chain(promoted.iter()) {
+                adts.visit_body(mir_body);
+                allocs.visit_body(mir_body);
+            }
+            let mut body = mir_body.to_mmir(tcx, *def_id);
+            body.promoted = promoted.to_mmir(tcx, *def_id);
+            self.mir_ast.push(body);
+        }
+        self.adt_table = adts.into_table();
+        self.alloc_table = allocs.into_table();
+        self.source_files = tables::source_files(tcx);
+        EARLY_MIR.with_borrow_mut(|early_mir| early_mir.clear());
+    }
 }
 
 impl Callbacks for MirHook {
+    fn config(&mut self, config : &mut rustc_interface::interface::Config) {
+        config.override_queries = match self.phase {
+            MirPhase::Built => Some(|_, providers| {
+                let _ = DEFAULT_PROVIDERS.set(providers.queries);
+                providers.queries.mir_built = mir_built;
+            }),
+            MirPhase::Promoted => Some(|_, providers| {
+                let _ = DEFAULT_PROVIDERS.set(providers.queries);
+                providers.queries.mir_promoted = mir_promoted;
+            }),
+            MirPhase::DropsElaborated => Some(|_, providers| {
+                let _ = DEFAULT_PROVIDERS.set(providers.queries);
+                providers.queries.mir_drops_elaborated_and_const_checked =
+                    mir_drops_elaborated_and_const_checked;
+            }),
+            MirPhase::Optimized => None,
+        };
+    }
+
     fn after_analysis<'tcx>(
         &mut self,
         _compiler: &rustc_interface::interface::Compiler,
         tcx: ty::TyCtxt<'tcx>,
     ) -> rustc_driver::Compilation {
-        for local_def_id in tcx.hir_body_owners() {
-            let def_id = local_def_id.to_def_id();
-            let mir_body = tcx.optimized_mir(def_id);
-            println!("Processing MIR for {:?} :\n {:#?}", def_id, mir_body);
-            self.mir_ast.push(mir_body.to_mmir(tcx, def_id));
-        }
+        self.extract(tcx);
         rustc_driver::Compilation::Stop
     }
 }
 
-#[ocaml::func]
-#[ocaml::sig("string -> body list")]
-pub fn get_mir(input : String) -> Vec<mir_types::Body> {
-    let sysroot = std::env::var("RUSTC_SYSROOT")
-        .unwrap_or("~/.rustup/toolchains/stable-x86_64-unknown-linux-gnu/".to_string());
-    println!("starting MIR extraction for {} with sysroot {}", input, sysroot);
-    let args = vec![
+// Command line of the compiler for `input`, the first argument being the
+// name of the program.
+fn rustc_args(input : String, config : &mir_types::Config) -> Vec<String> {
+    use mir_types::{CrateType, Edition};
+    let crate_type = match config.crate_type {
+        CrateType::Bin => "bin",
+        CrateType::Lib => "lib",
+        CrateType::Rlib => "rlib",
+        CrateType::Dylib => "dylib",
+        CrateType::Cdylib => "cdylib",
+        CrateType::Staticlib => "staticlib",
+        CrateType::ProcMacro => "proc-macro",
+    };
+    let edition = match config.edition {
+        Edition::Edition2015 => "2015",
+        Edition::Edition2018 => "2018",
+        Edition::Edition2021 => "2021",
+        Edition::Edition2024 => "2024",
+    };
+    let mut args = vec![
+        "rustc".to_string(),
         input,
         "--emit=mir".to_string(),
-        "--crate-type=bin".to_string(),
-        "--edition=2024".to_string(),
+        format!("--crate-type={}", crate_type),
+        format!("--edition={}", edition),
     ];
+    if let Some(target) = &config.target {
+        args.push(format!("--target={}", target));
+    }
+    if let Some(name) = &config.crate_name {
+        args.push(format!("--crate-name={}", name));
+    }
+    for cfg in &config.cfgs {
+        args.extend(["--cfg".to_string(), cfg.clone()]);
+    }
+    for (name, path) in &config.externs {
+        args.extend(["--extern".to_string(), format!("{}={}", name, path)]);
+    }
+    for path in &config.search_paths {
+        args.extend(["-L".to_string(), path.clone()]);
+    }
+    for flag in &config.unstable_flags {
+        args.extend(["-Z".to_string(), flag.clone()]);
+    }
+    for flag in &config.codegen_flags {
+        args.extend(["-C".to_string(), flag.clone()]);
+    }
+    args
+}
 
-    let mut mir_hook = MirHook { mir_ast : Vec::new() };
-    let _ = run_compiler(&args, &mut mir_hook);
-    mir_hook.mir_ast
+fn extract_mir(input : String, config : &mir_types::Config) -> Result<mir_types::CrateMir, String> {
+    let sysroot = std::env::var("RUSTC_SYSROOT")
+        .unwrap_or("~/.rustup/toolchains/stable-x86_64-unknown-linux-gnu/".to_string());
+    println!("starting MIR extraction for {} with sysroot {}", input, sysroot);
+    extract_with_args(&rustc_args(input.clone(), config), config.phase, true)
+        .ok_or_else(|| format!("compilation of {} failed", input))
+}
+
+// Runs the compiler with the command line `args`, the first argument being
+// the name of the program, and extracts the MIR of the crate at `phase`.
+// `None` if the crate does not compile.
+pub fn extract_with_args(
+    args : &[String],
+    phase : MirPhase,
+    dump : bool,
+) -> Option<mir_types::CrateMir> {
+    let mut mir_hook = MirHook {
+        phase,
+        dump,
+        mir_ast : Vec::new(),
+        adt_table : Vec::new(),
+        alloc_table : Vec::new(),
+        source_files : Vec::new(),
+    };
+    rustc_driver::catch_fatal_errors(|| run_compiler(args, &mut mir_hook)).ok()?;
+    Some(mir_types::CrateMir {
+        bodies : mir_hook.mir_ast,
+        adts : mir_hook.adt_table,
+        allocs : mir_hook.alloc_table,
+        source_files : mir_hook.source_files,
+    })
+}
+
+#[ocaml::func]
+#[ocaml::sig("string -> (crate_mir, string) result")]
+pub fn get_mir(input : String) -> Result<mir_types::CrateMir, String> {
+    extract_mir(input, &mir_types::Config::default())
+}
+
+#[ocaml::func]
+#[ocaml::sig("string -> mir_phase -> (crate_mir, string) result")]
+pub fn get_mir_at_phase(input : String, phase : MirPhase) -> Result<mir_types::CrateMir, String> {
+    extract_mir(input, &mir_types::Config { phase, ..Default::default() })
+}
+
+#[ocaml::func]
+#[ocaml::sig("unit -> config")]
+pub fn default_config() -> mir_types::Config {
+    mir_types::Config::default()
+}
+
+#[ocaml::func]
+#[ocaml::sig("string -> config -> (crate_mir, string) result")]
+pub fn get_mir_with_config(
+    input : String,
+    config : mir_types::Config,
+) -> Result<mir_types::CrateMir, String> {
+    extract_mir(input, &config)
+}
+
+#[ocaml::func]
+#[ocaml::sig("string -> config -> (workspace_crate list, string) result")]
+pub fn get_workspace_mir(
+    manifest : String,
+    config : mir_types::Config,
+) -> Result<Vec<mir_types::WorkspaceCrate>, String> {
+    workspace::extract(std::path::Path::new(&manifest), &config)
 }
@@ -9,14 +9,21 @@ where I : rustc_index::Idx, S: ocaml::ToValue {
 }
 */
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("Mut | Not")]
 pub enum Mutability {
     Mut,
     Not,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Safe | Unsafe")]
+pub enum Safety {
+    Safe,
+    Unsafe,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("Not | Neg | PtrMetadata")]
 pub enum UnOp {
     Not,
@@ -24,7 +31,7 @@ pub enum UnOp {
     PtrMetadata,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("Add | AddUnchecked | AddWithOverflow
     | Sub | SubUnchecked | SubWithOverflow
     | Mul | MulUnchecked | MulWithOverflow
@@ -45,13 +52,13 @@ pub enum BinOp {
     Rem,
     BitXor,
     BitAnd,
+    ShrUnchecked,
     BitOr,
     Shl,
-    ShlUnchecked,
     Shr,
-    ShrUnchecked,
     Eq,
     Ne,
+    ShlUnchecked,
     Lt,
     Le,
     Gt,
@@ -60,7 +67,7 @@ pub enum BinOp {
     Cmp,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("SizeOf | AlignOf | UbChecks | Unknown")]
 pub enum NullOp {
     SizeOf,
@@ -69,22 +76,115 @@ pub enum NullOp {
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
-#[ocaml::sig("int32 * int32")]
-pub enum Span {
-    Span(u32, u32),
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("ReifyFnPointer | UnsafeFnPointer | ClosureFnPointer of safety
+    | MutToConstPointer | ArrayToPointer | Unsize")]
+pub enum PointerCoercion {
+    ReifyFnPointer,
+    UnsafeFnPointer,
+    ClosureFnPointer(Safety),
+    MutToConstPointer,
+    ArrayToPointer,
+    Unsize,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("AsCast | Implicit")]
+pub enum CoercionSource {
+    AsCast,
+    Implicit,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("PointerExposeProvenance | PointerWithExposedProvenance
+    | PointerCoercion of pointer_coercion * coercion_source
+    | IntToInt | FloatToInt | FloatToFloat | IntToFloat
+    | PtrToPtr | FnPtrToPtr | Transmute")]
+pub enum CastKind {
+    PointerExposeProvenance,
+    PointerWithExposedProvenance,
+    PointerCoercion(PointerCoercion, CoercionSource),
+    IntToInt,
+    FloatToInt,
+    FloatToFloat,
+    IntToFloat,
+    PtrToPtr,
+    FnPtrToPtr,
+    Transmute,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("{ file : int32 ; line : int32 ; col : int32 }")]
+pub struct Location {
+    pub file : u32,
+    pub line : u32,
+    pub col : u32,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Root | MacroBang | MacroAttr | MacroDerive | AstPass | Desugaring")]
+pub enum ExpansionKind {
+    Root,
+    MacroBang,
+    MacroAttr,
+    MacroDerive,
+    AstPass,
+    Desugaring,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ kind : expansion_kind ; name : string ; call_site : span }")]
+pub struct Expansion {
+    pub kind : ExpansionKind,
+    pub name : String,
+    pub call_site : Span,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ lo : int32 ; hi : int32 ; start_loc : location ; end_loc : location ;
+    from_expansion : bool ; backtrace : expansion list }")]
+pub struct Span {
+    pub lo : u32,
+    pub hi : u32,
+    pub start_loc : Location,
+    pub end_loc : Location,
+    pub from_expansion : bool,
+    pub backtrace : Vec<Expansion>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Shallow | Deep")]
+pub enum FakeBorrowKind {
+    Shallow,
+    Deep,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Default | TwoPhaseBorrow | ClosureCapture")]
+pub enum MutBorrowKind {
+    Default,
+    TwoPhaseBorrow,
+    ClosureCapture,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
-#[ocaml::sig("Shared | Fake | Mut | Unknown")]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Shared | Fake of fake_borrow_kind | Mut of mut_borrow_kind | Unknown")]
 pub enum BorrowKind {
     Shared,
-    Fake,
-    Mut,
+    Fake(FakeBorrowKind),
+    Mut(MutBorrowKind),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Static | Erased | Other of string")]
+pub enum Region {
+    Static,
+    Erased,
+    Other(String),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("FnEntry | TwoPhase | Raw | Default")]
 pub enum Rkind {
     FnEntry,
@@ -93,7 +193,7 @@ pub enum Rkind {
     Default,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
 #[ocaml::sig("BinOp of bin_op | UnOp of un_op | FunctionCall | CastAs | CastUse")]
 pub enum ExprKind {
     BinOp(BinOp),
@@ -103,22 +203,114 @@ pub enum ExprKind {
     CastUse,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone, Copy)]
-#[ocaml::sig("ScalarInt of int32 | ScalarPtr of int32 * int32 * int
-    | ZeroSized | Slice of int32 * mutability | Indirect of int32 * int32")]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("ScalarInt of string * int | ScalarPtr of int32 * int32 * int
+    | ZeroSized | Slice of int32 * int32 * mutability | Indirect of int32 * int32")]
 pub enum ConstVal {
-    ScalarInt(u32),
+    ScalarInt(String, u8),
     ScalarPtr(u32, u32, u8),
     ZeroSized,
-    Slice(u32, Mutability),
+    Slice(u32, u32, Mutability),
     Indirect(u32, u32),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Struct | Enum | Union")]
+pub enum AdtKind {
+    Struct,
+    Enum,
+    Union,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ inputs : typ list ; output : typ ; abi : string ;
+    safety : safety ; c_variadic : bool }")]
+pub struct FnSig {
+    pub inputs : Vec<Typ>,
+    pub output : Box<Typ>,
+    pub abi : String,
+    pub safety : Safety,
+    pub c_variadic : bool,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Fn | FnMut | FnOnce")]
+pub enum ClosureKind {
+    Fn,
+    FnMut,
+    FnOnce,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("ByValue | ByUse | ByRef | ByUniqueRef | ByMutRef | Unknown")]
+pub enum CaptureKind {
+    ByValue,
+    ByUse,
+    ByRef,
+    ByUniqueRef,
+    ByMutRef,
+    Unknown,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ name : string ; typ : typ ; capture : capture_kind }")]
+pub struct Upvar {
+    pub name : String,
+    pub typ : Box<Typ>,
+    pub capture : CaptureKind,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ resume_ty : typ ; yield_ty : typ ; return_ty : typ }")]
+pub struct CoroutineSig {
+    pub resume_ty : Box<Typ>,
+    pub yield_ty : Box<Typ>,
+    pub return_ty : Box<Typ>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Projection | Inherent | Opaque | Free")]
+pub enum AliasKind {
+    Projection,
+    Inherent,
+    Opaque,
+    Free,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ path : string ; args : generic_arg list }")]
+pub struct TraitRef {
+    pub path : String,
+    pub args : Vec<GenericArg>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ principal : trait_ref option ; auto_traits : string list ; region : string }")]
+pub struct DynBound {
+    pub principal : Option<TraitRef>,
+    pub auto_traits : Vec<String>,
+    pub region : String,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Range of const * const | Or of ty_pattern list")]
+pub enum TyPattern {
+    Range(Box<Const>, Box<Const>),
+    Or(Vec<TyPattern>),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Bool | Char | Isize | I of int32 | USize
     | U of int32 | F of int32 | Str | Array of typ * const
     | Slice of typ | RawPtr of typ * mutability | Ref of typ * mutability
-    | Tuple of typ list | Unknown")]
+    | Tuple of typ list | Adt of string * adt_kind * generic_arg list
+    | FnDef of string * generic_arg list | FnPtr of fn_sig
+    | Closure of string * closure_kind * upvar list
+    | Coroutine of string * upvar list * coroutine_sig
+    | CoroutineClosure of string * closure_kind * upvar list
+    | Never | Param of int32 * string | Foreign of string | Pat of typ * ty_pattern
+    | UnsafeBinder of typ | Alias of alias_kind * string * generic_arg list
+    | Dynamic of dyn_bound | Unknown")]
 pub enum Typ {
     Bool,
     Char,
@@ -133,27 +325,62 @@ pub enum Typ {
     RawPtr(Box<Typ>, Mutability),
     Ref(Box<Typ>, Mutability),
     Tuple(Vec<Typ>),
+    Adt(String, AdtKind, Vec<GenericArg>),
+    FnDef(String, Vec<GenericArg>),
+    FnPtr(FnSig),
+    Closure(String, ClosureKind, Vec<Upvar>),
+    Coroutine(String, Vec<Upvar>, CoroutineSig),
+    CoroutineClosure(String, ClosureKind, Vec<Upvar>),
+    Never,
+    Param(u32, String),
+    Foreign(String),
+    Pat(Box<Typ>, TyPattern),
+    UnsafeBinder(Box<Typ>),
+    Alias(AliasKind, String, Vec<GenericArg>),
+    Dynamic(DynBound),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ def_path : string ; args : generic_arg list ; promoted : int32 option ;
+    value : const_val option ; typ : typ }")]
+pub struct UnevaluatedConst {
+    pub def_path : String,
+    pub args : Vec<GenericArg>,
+    pub promoted : Option<u32>,
+    pub value : Option<ConstVal>,
+    pub typ : Box<Typ>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Ty of typ * const | Val of const_val * typ
-    | Param of int32 | Expr of expr_kind * arg list | Unknown")]
+    | Param of int32 | Expr of expr_kind * arg list
+    | Unevaluated of unevaluated_const | Unknown")]
 pub enum Const {
     Ty(Box<Typ>, Box<Const>),
     Val(ConstVal, Box<Typ>),
     Param(u32),
     Expr(ExprKind, Vec<Arg>),
+    Unevaluated(UnevaluatedConst),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("typ * const")]
+#[serde(untagged)]
 pub enum Arg {
     Arg(Box<Typ>, Box<Const>),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Type of typ | Const of const | Lifetime")]
+pub enum GenericArg {
+    Type(Box<Typ>),
+    Const(Box<Const>),
+    Lifetime,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Deref | Field of int32 * typ | Index of int32
     | ConstantIndex of int32 * int32 * bool | Subslice of int32 * int32 * bool
     | Downcast of int32 | OpaqueCast of typ | Subtype of typ | UnwrapUnsafeBinder of typ")]
@@ -169,16 +396,55 @@ pub enum Projection {
     UnwrapUnsafeBinder(Box<Typ>),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+// Projection of a user type annotation, which refers to no local and carries
+// no types.
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Deref | Field of int32 | Index
+    | ConstantIndex of int32 * int32 * bool | Subslice of int32 * int32 * bool
+    | Downcast of int32 | OpaqueCast | Subtype | UnwrapUnsafeBinder")]
+pub enum ProjectionKind {
+    Deref,
+    Field(u32),
+    Index,
+    ConstantIndex(u32, u32, bool),
+    Subslice(u32, u32, bool),
+    Downcast(u32),
+    OpaqueCast,
+    Subtype,
+    UnwrapUnsafeBinder,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ local : int32 ; proj : projection list }")]
 pub struct Place {
     pub local : u32,
     pub proj : Vec<Projection>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ by_ref : mutability option ; mutability : mutability ;
+    opt_ty_info : span option ; pat_span : span }")]
+pub struct VarBinding {
+    pub by_ref : Option<Mutability>,
+    pub mutability : Mutability,
+    pub opt_ty_info : Option<Span>,
+    pub pat_span : Span,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Imm | Mut | RefImm | RefMut | NoSelf")]
+pub enum ImplicitSelfKind {
+    Imm,
+    Mut,
+    RefImm,
+    RefMut,
+    NoSelf,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("ConstRef of int32 | AggregateTemp | DerefTemp | FakeBorrow | Boring
-    | StaticRef of int32 | Unknown")]
+    | StaticRef of int32 | User of var_binding | ImplicitSelf of implicit_self_kind
+    | RefForGuard | Unknown")]
 pub enum LocalInfo {
     ConstRef(u32),
     StaticRef(u32),
@@ -186,19 +452,25 @@ pub enum LocalInfo {
     DerefTemp,
     FakeBorrow,
     Boring,
+    User(VarBinding),
+    ImplicitSelf(ImplicitSelfKind),
+    RefForGuard,
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
-#[ocaml::sig("{ scope : int32 ; local : local_info ; typ : typ ; mut : mutability }")]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ scope : int32 ; local : local_info ; typ : typ ; mut : mutability ;
+    span : span ; user_ty : user_type_projection list }")]
 pub struct LocalDecl {
     pub scope : u32,
     pub local : LocalInfo,
     pub typ : Box<Typ>,
     pub r#mut : Mutability,
+    pub span : Span,
+    pub user_ty : Vec<UserTypeProjection>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Copy of place | Move of place | Constant of const")]
 pub enum Operand {
     Copy(Place),
@@ -206,24 +478,41 @@ pub enum Operand {
     Constant(Box<Const>),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Assume of operand | CopyNonOverlapping of operand * operand * operand")]
 pub enum Intrinsic {
     Assume(Operand),
     CopyNonOverlapping(Operand, Operand, Operand),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Array of typ | Tuple
+    | Adt of string * int32 * generic_arg list * int32 option
+    | Closure of string * generic_arg list | Coroutine of string * generic_arg list
+    | CoroutineClosure of string * generic_arg list | RawPtr of typ * mutability")]
+pub enum AggregateKind {
+    Array(Box<Typ>),
+    Tuple,
+    Adt(String, u32, Vec<GenericArg>, Option<u32>),
+    Closure(String, Vec<GenericArg>),
+    Coroutine(String, Vec<GenericArg>),
+    CoroutineClosure(String, Vec<GenericArg>),
+    RawPtr(Box<Typ>, Mutability),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Use of operand | Repeat of operand * const
-    | Ref of borrow_kind * place | RawPtr of mutability * place
+    | Ref of region * borrow_kind * place | RawPtr of mutability * place
     | Len of place | BinaryOp of bin_op * operand * operand
     | NullaryOp of null_op | UnaryOp of un_op * operand | Discriminant of place
     | ShallowInitBox of operand * typ | CopyForDeref of place
-    | WrapUnsafeBinder of operand * typ | Unknown")]
+    | WrapUnsafeBinder of operand * typ
+    | Aggregate of aggregate_kind * operand list
+    | Cast of cast_kind * operand * typ | Unknown")]
 pub enum Rvalue {
     Use(Operand),
     Repeat(Operand, Box<Const>),
-    Ref(BorrowKind, Place),
+    Ref(Region, BorrowKind, Place),
     RawPtr(Mutability, Place),
     Len(Place),
     BinaryOp(BinOp, Operand, Operand),
@@ -233,16 +522,68 @@ pub enum Rvalue {
     ShallowInitBox(Operand, Box<Typ>),
     CopyForDeref(Place),
     WrapUnsafeBinder(Operand, Box<Typ>),
+    Aggregate(AggregateKind, Vec<Operand>),
+    Cast(CastKind, Operand, Box<Typ>),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("ForMatchGuard | ForMatchedPlace of string option | ForGuardBinding
+    | ForLet of string option | ForIndex")]
+pub enum FakeReadCause {
+    ForMatchGuard,
+    ForMatchedPlace(Option<String>),
+    ForGuardBinding,
+    ForLet(Option<String>),
+    ForIndex,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Covariant | Invariant | Contravariant | Bivariant")]
+pub enum Variance {
+    Covariant,
+    Invariant,
+    Contravariant,
+    Bivariant,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("SpanMarker | BlockMarker of int32 | VirtualCounter of int32
+    | CondBitmapUpdate of int32 * int32 | TestVectorBitmapUpdate of int32 * int32")]
+pub enum CoverageKind {
+    SpanMarker,
+    BlockMarker(u32),
+    VirtualCounter(u32),
+    CondBitmapUpdate(u32, u32),
+    TestVectorBitmapUpdate(u32, u32),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Edition2024")]
+pub enum DropHintReason {
+    Edition2024,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ base : int32 ; projs : projection_kind list }")]
+pub struct UserTypeProjection {
+    pub base : u32,
+    pub projs : Vec<ProjectionKind>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Assign of place * rvalue | SetDiscriminant of place * int32
     | Deinit of place | StorageLive of int32 | StorageDead of int32 
     | Retag of rkind * place | PlaceMention of place | Intrinsic of intrinsic
     | Nop | ConstEvalCounter | Goto of int32 | SwitchInt of operand * targets
     | UnwindResume | UnwindTerminate | Unreachable | Return | Drop of drop_info
-    | Call of call_info | Assert of assert_info | CoroutineDrop | Unknown")]
+    | Call of call_info | Assert of assert_info | CoroutineDrop
+    | Yield of yield_info | FalseEdge of int32 * int32
+    | FalseUnwind of int32 * unwind_action | InlineAsm of inline_asm_info
+    | TailCall of operand * operand list | FakeRead of fake_read_cause * place
+    | AscribeUserType of place * user_type_projection * variance
+    | Coverage of coverage_kind | BackwardIncompatibleDropHint of place * drop_hint_reason
+    | Unknown")]
 pub enum StatementKind {
     Assign(Place, Rvalue),
     SetDiscriminant(Place, u32),
@@ -259,33 +600,97 @@ pub enum StatementKind {
     UnwindResume,
     UnwindTerminate,
     Unreachable,
-    CoroutineDrop,
     Return,
     Drop(DropInfo),
     Call(CallInfo),
     Assert(AssertInfo),
+    CoroutineDrop,
+    Yield(YieldInfo),
+    FalseEdge(u32, u32),
+    FalseUnwind(u32, UnwindAction),
+    InlineAsm(InlineAsmInfo),
+    TailCall(Operand, Vec<Operand>),
+    FakeRead(FakeReadCause, Place),
+    AscribeUserType(Place, UserTypeProjection, Variance),
+    Coverage(CoverageKind),
+    BackwardIncompatibleDropHint(Place, DropHintReason),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
-#[ocaml::sig("{ skind : statement_kind ; span : span ; scope : int32 }")]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Safe | UnsafeBlock | UnsafeFn | BuiltinUnsafe | Unknown")]
+pub enum SafetyContext {
+    Safe,
+    UnsafeBlock,
+    UnsafeFn,
+    BuiltinUnsafe,
+    Unknown,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ skind : statement_kind ; span : span ; scope : int32 ;
+    safety : safety_context }")]
 pub struct Statement {
     pub skind : StatementKind,
     pub span : Span,
     pub scope : u32,
+    pub safety : SafetyContext,
 }
 
 // todo : Coherce trait impl ?
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
-#[ocaml::sig("{ targets : int32 list ; values : int32 list }")]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ targets : int32 list ; values : string list ; otherwise : int32 }")]
 pub struct Targets {
     pub targets : Vec<u32>,
-    pub values : Vec<u32>,
+    pub values : Vec<String>,
+    pub otherwise : u32,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Item | Intrinsic | VTableShim | ReifyShim | FnPtrShim of typ
+    | Virtual of int32 | ClosureOnceShim | ConstructCoroutineInClosureShim
+    | ThreadLocalShim | DropGlue of typ option | CloneShim of typ
+    | FnPtrAddrShim of typ | FutureDropPollShim of typ * typ
+    | AsyncDropGlueCtorShim of typ | AsyncDropGlue of typ")]
+pub enum InstanceKind {
+    Item,
+    Intrinsic,
+    VTableShim,
+    ReifyShim,
+    FnPtrShim(Box<Typ>),
+    Virtual(u32),
+    ClosureOnceShim,
+    ConstructCoroutineInClosureShim,
+    ThreadLocalShim,
+    DropGlue(Option<Box<Typ>>),
+    CloneShim(Box<Typ>),
+    FnPtrAddrShim(Box<Typ>),
+    FutureDropPollShim(Box<Typ>, Box<Typ>),
+    AsyncDropGlueCtorShim(Box<Typ>),
+    AsyncDropGlue(Box<Typ>),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ def_path : string ; kind : instance_kind ; args : generic_arg list }")]
+pub struct Instance {
+    pub def_path : String,
+    pub kind : InstanceKind,
+    pub args : Vec<GenericArg>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Resolved of instance | TooGeneric | Indirect | Failed")]
+pub enum CallTarget {
+    Resolved(Instance),
+    TooGeneric,
+    Indirect,
+    Failed,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ func : operand ; args : operand list ; dest : place ;
-    target : int32 option ; unwind : unwind_action ; span : span }")]
+    target : int32 option ; unwind : unwind_action ; span : span ;
+    resolved : call_target }")]
 pub struct CallInfo {
     pub func : Operand,
     pub args : Vec<Operand>,
@@ -293,14 +698,48 @@ pub struct CallInfo {
     pub target : Option<u32>,
     pub unwind : UnwindAction,
     pub span : Span,
+    pub resolved : CallTarget,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Async | Gen | AsyncGen")]
+pub enum CoroutineDesugaring {
+    Async,
+    Gen,
+    AsyncGen,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Block | Closure | Fn")]
+pub enum CoroutineSource {
+    Block,
+    Closure,
+    Fn,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Static | Movable")]
+pub enum Movability {
+    Static,
+    Movable,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Desugared of coroutine_desugaring * coroutine_source
+    | Coroutine of movability")]
+pub enum CoroutineKind {
+    Desugared(CoroutineDesugaring, CoroutineSource),
+    Coroutine(Movability),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("BoundsCheck of operand * operand
     | Overflow of bin_op * operand * operand
     | OverflowNeg of operand | DivisionByZero of operand
     | RemainderByZero of operand | MisalignedPointerDereference of operand * operand
-    | NullPointerDereference | Unknown")]
+    | NullPointerDereference | ResumedAfterReturn of coroutine_kind
+    | ResumedAfterPanic of coroutine_kind | ResumedAfterDrop of coroutine_kind
+    | InvalidEnumConstruction of operand | Unknown")]
 pub enum AssertMessage {
     BoundsCheck(Operand, Operand),
     Overflow(BinOp, Operand, Operand),
@@ -309,10 +748,14 @@ pub enum AssertMessage {
     RemainderByZero(Operand),
     MisalignedPointerDereference(Operand, Operand),
     NullPointerDereference,
+    ResumedAfterReturn(CoroutineKind),
+    ResumedAfterPanic(CoroutineKind),
+    ResumedAfterDrop(CoroutineKind),
+    InvalidEnumConstruction(Operand),
     Unknown,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ place : place ; target : int32 ;
     unwind : unwind_action ; replace : bool ; drop : int32 option }")]
 pub struct DropInfo {
@@ -323,8 +766,8 @@ pub struct DropInfo {
     pub drop : Option<u32>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
-#[ocaml::sig("{ cond : operand ; expected : bool ; msg : string ;
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ cond : operand ; expected : bool ; msg : assert_message ;
     target : int32 ; unwind : unwind_action }")]
 pub struct AssertInfo {
     pub cond : Operand,
@@ -334,15 +777,50 @@ pub struct AssertInfo {
     pub unwind : UnwindAction,
 }
 
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ value : operand ; resume : int32 ; resume_arg : place ;
+    drop : int32 option }")]
+pub struct YieldInfo {
+    pub value : Operand,
+    pub resume : u32,
+    pub resume_arg : Place,
+    pub drop : Option<u32>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("In of string * operand | Out of string * bool * place option
+    | InOut of string * bool * operand * place option | Const of const
+    | SymFn of const | SymStatic of string | Label of int32")]
+pub enum AsmOperand {
+    In(String, Operand),
+    Out(String, bool, Option<Place>),
+    InOut(String, bool, Operand, Option<Place>),
+    Const(Const),
+    SymFn(Const),
+    SymStatic(String),
+    Label(u32),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ template : string ; operands : asm_operand list ;
+    options : string list ; targets : int32 list ; unwind : unwind_action }")]
+pub struct InlineAsmInfo {
+    pub template : String,
+    pub operands : Vec<AsmOperand>,
+    pub options : Vec<String>,
+    pub targets : Vec<u32>,
+    pub unwind : UnwindAction,
+}
+
 // todo : Coherce trait impl ?
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ statements : statement list ; is_cleanup : bool }")]
 pub struct BasicBlock {
     pub statements : Vec<Statement>,
     pub is_cleanup : bool,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Continue | Unreachable | Terminate | Cleanup of int32")]
 pub enum UnwindAction {
     Continue,
@@ -351,40 +829,320 @@ pub enum UnwindAction {
     Cleanup(u32),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("Place of place | Const of const")]
 pub enum VarDebugInfoContent {
     Place(Place),
     Const(Const),
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ ty : typ ; projection : projection list }")]
 pub struct VarDebugInfoFragment {
     pub ty : Typ,
     pub projection : Vec<Projection>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
 #[ocaml::sig("{ content : var_debug_info_content ; scope : int32 ; name : string ;
     arg_index : int32 option ; composite : var_debug_info_fragment option }")]
 pub struct VarDebugInfo {
-    pub value : VarDebugInfoContent,
+    pub content : VarDebugInfoContent,
     pub scope : u32,
     pub name : String,
     pub arg_index : Option<u32>,
     pub composite : Option<Box<VarDebugInfoFragment>>,
 }
 
-#[derive(ocaml::FromValue, ocaml::ToValue, Clone)]
-#[ocaml::sig("{ stmts : basic_block list ; local_decls : local_decl list ;
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ impl_def_path : string ; self_ty : typ }")]
+pub struct UserSelfTy {
+    pub impl_def_path : String,
+    pub self_ty : Typ,
+}
+
+// Type written by the user: a type, or the type of a path given its generic
+// arguments (e.g. `Vec::<u8>::new`). The parts left to inference (`_`) are
+// `Unknown`.
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Ty of typ | TypeOf of string * generic_arg list * user_self_ty option")]
+pub enum UserTypeKind {
+    Ty(Typ),
+    TypeOf(String, Vec<GenericArg>, Option<UserSelfTy>),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ user_ty : user_type_kind ; span : span ; inferred_ty : typ }")]
+pub struct UserTypeAnnotation {
+    pub user_ty : UserTypeKind,
+    pub span : Span,
+    pub inferred_ty : Typ,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("DerefOfRawPointer | CallToUnsafeFunction of string option
+    | UseOfMutableStatic of string | UseOfExternStatic of string
+    | AccessToUnionField | UseOfInlineAssembly")]
+pub enum UnsafeOpKind {
+    DerefOfRawPointer,
+    CallToUnsafeFunction(Option<String>),
+    UseOfMutableStatic(String),
+    UseOfExternStatic(String),
+    AccessToUnionField,
+    UseOfInlineAssembly,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ kind : unsafe_op_kind ; block : int32 ; statement : int32 ;
+    span : span ; safety : safety_context }")]
+pub struct UnsafeOp {
+    pub kind : UnsafeOpKind,
+    pub block : u32,
+    pub statement : u32,
+    pub span : Span,
+    pub safety : SafetyContext,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ owner : string ; local_id : int32 }")]
+pub struct HirId {
+    pub owner : String,
+    pub local_id : u32,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ span : span ; parent : int32 option ; inlined : (instance * span) option ;
+    inlined_parent : int32 option ; lint_root : hir_id option }")]
+pub struct SourceScope {
+    pub span : Span,
+    pub parent : Option<u32>,
+    pub inlined : Option<(Instance, Span)>,
+    pub inlined_parent : Option<u32>,
+    pub lint_root : Option<HirId>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Fn | AssocFn | Closure | Const | AssocConst | Static of mutability
+    | AnonConst | InlineConst | Ctor | SyntheticCoroutineBody | Other of string")]
+pub enum DefKind {
+    Fn,
+    AssocFn,
+    Closure,
+    Const,
+    AssocConst,
+    Static(Mutability),
+    AnonConst,
+    InlineConst,
+    Ctor,
+    SyntheticCoroutineBody,
+    Other(String),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Public | Restricted of string")]
+pub enum Visibility {
+    Public,
+    Restricted(String),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ def_path : string ; name : string option ; kind : def_kind ;
+    parent : string option ; visibility : visibility ; is_unsafe : bool ;
+    is_const : bool ; is_async : bool ; promoted : int32 option }")]
+pub struct BodyHeader {
+    pub def_path : String,
+    pub name : Option<String>,
+    pub kind : DefKind,
+    pub parent : Option<String>,
+    pub visibility : Visibility,
+    pub is_unsafe : bool,
+    pub is_const : bool,
+    pub is_async : bool,
+    pub promoted : Option<u32>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Built | Promoted | DropsElaborated | Optimized")]
+pub enum MirPhase {
+    Built,
+    Promoted,
+    DropsElaborated,
+    Optimized,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ header : body_header ; phase : mir_phase ; stmts : basic_block list ; local_decls : local_decl list ;
     var_debug_info : var_debug_info list ; arg_count : int32 ;
-    spread_arg : int32 option ; span : span }")]
+    spread_arg : int32 option ; span : span ;
+    user_type_annotations : user_type_annotation list ;
+    source_scopes : source_scope list ; unsafe_ops : unsafe_op list ;
+    promoted : body list }")]
 pub struct Body {
+    pub header : BodyHeader,
+    pub phase : MirPhase,
     pub stmts: Vec<BasicBlock>,
     pub local_decls: Vec<LocalDecl>,
-    pub arg_count : usize,
     pub var_debug_info: Vec<VarDebugInfo>,
+    pub arg_count : usize,
     pub spread_arg : Option<usize>,
     pub span : Span,
-}
\ No newline at end of file
+    pub user_type_annotations : Vec<UserTypeAnnotation>,
+    pub source_scopes : Vec<SourceScope>,
+    pub unsafe_ops : Vec<UnsafeOp>,
+    pub promoted : Vec<Body>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ name : string ; typ : typ }")]
+pub struct FieldDef {
+    pub name : String,
+    pub typ : Box<Typ>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ name : string ; discr : string option ; fields : field_def list }")]
+pub struct VariantDef {
+    pub name : String,
+    pub discr : Option<String>,
+    pub fields : Vec<FieldDef>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ c : bool ; transparent : bool ; simd : bool ; linear : bool ;
+    int : typ option ; align : int32 option ; pack : int32 option }")]
+pub struct ReprOptions {
+    pub c : bool,
+    pub transparent : bool,
+    pub simd : bool,
+    pub linear : bool,
+    pub int : Option<Box<Typ>>,
+    pub align : Option<u32>,
+    pub pack : Option<u32>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ path : string ; kind : adt_kind ; variants : variant_def list ;
+    repr : repr_options }")]
+pub struct AdtDef {
+    pub path : String,
+    pub kind : AdtKind,
+    pub variants : Vec<VariantDef>,
+    pub repr : ReprOptions,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ offset : int32 ; target : int32 }")]
+pub struct Relocation {
+    pub offset : u32,
+    pub target : u32,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ bytes : int list ; align : int32 ; mutability : mutability ;
+    relocations : relocation list }")]
+pub struct Allocation {
+    pub bytes : Vec<u8>,
+    pub align : u32,
+    pub mutability : Mutability,
+    pub relocations : Vec<Relocation>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("Memory of allocation | Function of instance | VTable of typ
+    | Static of string")]
+pub enum GlobalAlloc {
+    Memory(Allocation),
+    Function(Instance),
+    VTable(Box<Typ>),
+    Static(String),
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ id : int32 ; kind : global_alloc }")]
+pub struct Alloc {
+    pub id : u32,
+    pub kind : GlobalAlloc,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ name : string ; start_pos : int32 ; end_pos : int32 ; src : string option }")]
+pub struct SourceFile {
+    pub name : String,
+    pub start_pos : u32,
+    pub end_pos : u32,
+    pub src : Option<String>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ bodies : body list ; adts : adt_def list ; allocs : alloc list ;
+    source_files : source_file list }")]
+pub struct CrateMir {
+    pub bodies : Vec<Body>,
+    pub adts : Vec<AdtDef>,
+    pub allocs : Vec<Alloc>,
+    pub source_files : Vec<SourceFile>,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Bin | Lib | Rlib | Dylib | Cdylib | Staticlib | ProcMacro")]
+pub enum CrateType {
+    Bin,
+    Lib,
+    Rlib,
+    Dylib,
+    Cdylib,
+    Staticlib,
+    ProcMacro,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone, Copy)]
+#[ocaml::sig("Edition2015 | Edition2018 | Edition2021 | Edition2024")]
+pub enum Edition {
+    Edition2015,
+    Edition2018,
+    Edition2021,
+    Edition2024,
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ crate_type : crate_type ; edition : edition ; target : string option ;
+    crate_name : string option ; cfgs : string list ; externs : (string * string) list ;
+    search_paths : string list ; unstable_flags : string list ;
+    codegen_flags : string list ; phase : mir_phase }")]
+pub struct Config {
+    pub crate_type : CrateType,
+    pub edition : Edition,
+    pub target : Option<String>,
+    pub crate_name : Option<String>,
+    pub cfgs : Vec<String>,
+    pub externs : Vec<(String, String)>,
+    pub search_paths : Vec<String>,
+    pub unstable_flags : Vec<String>,
+    pub codegen_flags : Vec<String>,
+    pub phase : MirPhase,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            crate_type : CrateType::Bin,
+            edition : Edition::Edition2024,
+            target : None,
+            crate_name : None,
+            cfgs : Vec::new(),
+            externs : Vec::new(),
+            search_paths : Vec::new(),
+            unstable_flags : Vec::new(),
+            codegen_flags : Vec::new(),
+            phase : MirPhase::Optimized,
+        }
+    }
+}
+
+#[derive(ocaml::FromValue, ocaml::ToValue, serde::Serialize, Clone)]
+#[ocaml::sig("{ package : string ; target : string ; crate_mir : crate_mir }")]
+pub struct WorkspaceCrate {
+    pub package : String,
+    pub target : String,
+    pub crate_mir : CrateMir,
+}
@@ -7,6 +7,7 @@ open! Bigarray
 (* file: mir_types.rs *)
 
 type mutability = Mut | Not
+and safety = Safe | Unsafe
 and un_op = Not | Neg | PtrMetadata
 and bin_op = Add | AddUnchecked | AddWithOverflow
     | Sub | SubUnchecked | SubWithOverflow
@@ -15,64 +16,198 @@ and bin_op = Add | AddUnchecked | AddWithOverflow
     | BitOr | Shl | Shr | Eq | Ne | ShlUnchecked
     | Lt | Le | Gt | Ge | Offset | Cmp
 and null_op = SizeOf | AlignOf | UbChecks | Unknown
-and span = int32 * int32
-and borrow_kind = Shared | Fake | Mut | Unknown
+and pointer_coercion = ReifyFnPointer | UnsafeFnPointer | ClosureFnPointer of safety
+    | MutToConstPointer | ArrayToPointer | Unsize
+and coercion_source = AsCast | Implicit
+and cast_kind = PointerExposeProvenance | PointerWithExposedProvenance
+    | PointerCoercion of pointer_coercion * coercion_source
+    | IntToInt | FloatToInt | FloatToFloat | IntToFloat
+    | PtrToPtr | FnPtrToPtr | Transmute
+and location = { file : int32 ; line : int32 ; col : int32 }
+and expansion_kind = Root | MacroBang | MacroAttr | MacroDerive | AstPass | Desugaring
+and expansion = { kind : expansion_kind ; name : string ; call_site : span }
+and span = { lo : int32 ; hi : int32 ; start_loc : location ; end_loc : location ;
+    from_expansion : bool ; backtrace : expansion list }
+and fake_borrow_kind = Shallow | Deep
+and mut_borrow_kind = Default | TwoPhaseBorrow | ClosureCapture
+and borrow_kind = Shared | Fake of fake_borrow_kind | Mut of mut_borrow_kind | Unknown
+and region = Static | Erased | Other of string
 and rkind = FnEntry | TwoPhase | Raw | Default
 and expr_kind = BinOp of bin_op | UnOp of un_op | FunctionCall | CastAs | CastUse
-and const_val = ScalarInt of int32 | ScalarPtr of int32 * int32 * int
-    | ZeroSized | Slice of int32 * mutability | Indirect of int32 * int32
+and const_val = ScalarInt of string * int | ScalarPtr of int32 * int32 * int
+    | ZeroSized | Slice of int32 * int32 * mutability | Indirect of int32 * int32
+and adt_kind = Struct | Enum | Union
+and fn_sig = { inputs : typ list ; output : typ ; abi : string ;
+    safety : safety ; c_variadic : bool }
+and closure_kind = Fn | FnMut | FnOnce
+and capture_kind = ByValue | ByUse | ByRef | ByUniqueRef | ByMutRef | Unknown
+and upvar = { name : string ; typ : typ ; capture : capture_kind }
+and coroutine_sig = { resume_ty : typ ; yield_ty : typ ; return_ty : typ }
+and alias_kind = Projection | Inherent | Opaque | Free
+and trait_ref = { path : string ; args : generic_arg list }
+and dyn_bound = { principal : trait_ref option ; auto_traits : string list ; region : string }
+and ty_pattern = Range of const * const | Or of ty_pattern list
 and typ = Bool | Char | Isize | I of int32 | USize
     | U of int32 | F of int32 | Str | Array of typ * const
     | Slice of typ | RawPtr of typ * mutability | Ref of typ * mutability
-    | Tuple of typ list | Unknown
+    | Tuple of typ list | Adt of string * adt_kind * generic_arg list
+    | FnDef of string * generic_arg list | FnPtr of fn_sig
+    | Closure of string * closure_kind * upvar list
+    | Coroutine of string * upvar list * coroutine_sig
+    | CoroutineClosure of string * closure_kind * upvar list
+    | Never | Param of int32 * string | Foreign of string | Pat of typ * ty_pattern
+    | UnsafeBinder of typ | Alias of alias_kind * string * generic_arg list
+    | Dynamic of dyn_bound | Unknown
+and unevaluated_const = { def_path : string ; args : generic_arg list ; promoted : int32 option ;
+    value : const_val option ; typ : typ }
 and const = Ty of typ * const | Val of const_val * typ
-    | Param of int32 | Expr of expr_kind * arg list | Unknown
+    | Param of int32 | Expr of expr_kind * arg list
+    | Unevaluated of unevaluated_const | Unknown
 and arg = typ * const
+and generic_arg = Type of typ | Const of const | Lifetime
 and projection = Deref | Field of int32 * typ | Index of int32
     | ConstantIndex of int32 * int32 * bool | Subslice of int32 * int32 * bool
     | Downcast of int32 | OpaqueCast of typ | Subtype of typ | UnwrapUnsafeBinder of typ
+and projection_kind = Deref | Field of int32 | Index
+    | ConstantIndex of int32 * int32 * bool | Subslice of int32 * int32 * bool
+    | Downcast of int32 | OpaqueCast | Subtype | UnwrapUnsafeBinder
 and place = { local : int32 ; proj : projection list }
+and var_binding = { by_ref : mutability option ; mutability : mutability ;
+    opt_ty_info : span option ; pat_span : span }
+and implicit_self_kind = Imm | Mut | RefImm | RefMut | NoSelf
 and local_info = ConstRef of int32 | AggregateTemp | DerefTemp | FakeBorrow | Boring
-    | StaticRef of int32 | Unknown
-and local_decl = { scope : int32 ; local : local_info ; typ : typ ; mut : mutability }
+    | StaticRef of int32 | User of var_binding | ImplicitSelf of implicit_self_kind
+    | RefForGuard | Unknown
+and local_decl = { scope : int32 ; local : local_info ; typ : typ ; mut : mutability ;
+    span : span ; user_ty : user_type_projection list }
 and operand = Copy of place | Move of place | Constant of const
 and intrinsic = Assume of operand | CopyNonOverlapping of operand * operand * operand
+and aggregate_kind = Array of typ | Tuple
+    | Adt of string * int32 * generic_arg list * int32 option
+    | Closure of string * generic_arg list | Coroutine of string * generic_arg list
+    | CoroutineClosure of string * generic_arg list | RawPtr of typ * mutability
 and rvalue = Use of operand | Repeat of operand * const
-    | Ref of borrow_kind * place | RawPtr of mutability * place
+    | Ref of region * borrow_kind * place | RawPtr of mutability * place
     | Len of place | BinaryOp of bin_op * operand * operand
     | NullaryOp of null_op | UnaryOp of un_op * operand | Discriminant of place
     | ShallowInitBox of operand * typ | CopyForDeref of place
-    | WrapUnsafeBinder of operand * typ | Unknown
+    | WrapUnsafeBinder of operand * typ
+    | Aggregate of aggregate_kind * operand list
+    | Cast of cast_kind * operand * typ | Unknown
+and fake_read_cause = ForMatchGuard | ForMatchedPlace of string option | ForGuardBinding
+    | ForLet of string option | ForIndex
+and variance = Covariant | Invariant | Contravariant | Bivariant
+and coverage_kind = SpanMarker | BlockMarker of int32 | VirtualCounter of int32
+    | CondBitmapUpdate of int32 * int32 | TestVectorBitmapUpdate of int32 * int32
+and drop_hint_reason = Edition2024
+and user_type_projection = { base : int32 ; projs : projection_kind list }
 and statement_kind = Assign of place * rvalue | SetDiscriminant of place * int32
     | Deinit of place | StorageLive of int32 | StorageDead of int32 
     | Retag of rkind * place | PlaceMention of place | Intrinsic of intrinsic
     | Nop | ConstEvalCounter | Goto of int32 | SwitchInt of operand * targets
     | UnwindResume | UnwindTerminate | Unreachable | Return | Drop of drop_info
-    | Call of call_info | Assert of assert_info | CoroutineDrop | Unknown
-and statement = { skind : statement_kind ; span : span ; scope : int32 }
-and targets = { targets : int32 list ; values : int32 list }
+    | Call of call_info | Assert of assert_info | CoroutineDrop
+    | Yield of yield_info | FalseEdge of int32 * int32
+    | FalseUnwind of int32 * unwind_action | InlineAsm of inline_asm_info
+    | TailCall of operand * operand list | FakeRead of fake_read_cause * place
+    | AscribeUserType of place * user_type_projection * variance
+    | Coverage of coverage_kind | BackwardIncompatibleDropHint of place * drop_hint_reason
+    | Unknown
+and safety_context = Safe | UnsafeBlock | UnsafeFn | BuiltinUnsafe | Unknown
+and statement = { skind : statement_kind ; span : span ; scope : int32 ;
+    safety : safety_context }
+and targets = { targets : int32 list ; values : string list ; otherwise : int32 }
+and instance_kind = Item | Intrinsic | VTableShim | ReifyShim | FnPtrShim of typ
+    | Virtual of int32 | ClosureOnceShim | ConstructCoroutineInClosureShim
+    | ThreadLocalShim | DropGlue of typ option | CloneShim of typ
+    | FnPtrAddrShim of typ | FutureDropPollShim of typ * typ
+    | AsyncDropGlueCtorShim of typ | AsyncDropGlue of typ
+and instance = { def_path : string ; kind : instance_kind ; args : generic_arg list }
+and call_target = Resolved of instance | TooGeneric | Indirect | Failed
 and call_info = { func : operand ; args : operand list ; dest : place ;
-    target : int32 option ; unwind : unwind_action ; span : span }
+    target : int32 option ; unwind : unwind_action ; span : span ;
+    resolved : call_target }
+and coroutine_desugaring = Async | Gen | AsyncGen
+and coroutine_source = Block | Closure | Fn
+and movability = Static | Movable
+and coroutine_kind = Desugared of coroutine_desugaring * coroutine_source
+    | Coroutine of movability
 and assert_message = BoundsCheck of operand * operand
     | Overflow of bin_op * operand * operand
     | OverflowNeg of operand | DivisionByZero of operand
     | RemainderByZero of operand | MisalignedPointerDereference of operand * operand
-    | NullPointerDereference | Unknown
+    | NullPointerDereference | ResumedAfterReturn of coroutine_kind
+    | ResumedAfterPanic of coroutine_kind | ResumedAfterDrop of coroutine_kind
+    | InvalidEnumConstruction of operand | Unknown
 and drop_info = { place : place ; target : int32 ;
     unwind : unwind_action ; replace : bool ; drop : int32 option }
-and assert_info = { cond : operand ; expected : bool ; msg : string ;
+and assert_info = { cond : operand ; expected : bool ; msg : assert_message ;
     target : int32 ; unwind : unwind_action }
+and yield_info = { value : operand ; resume : int32 ; resume_arg : place ;
+    drop : int32 option }
+and asm_operand = In of string * operand | Out of string * bool * place option
+    | InOut of string * bool * operand * place option | Const of const
+    | SymFn of const | SymStatic of string | Label of int32
+and inline_asm_info = { template : string ; operands : asm_operand list ;
+    options : string list ; targets : int32 list ; unwind : unwind_action }
 and basic_block = { statements : statement list ; is_cleanup : bool }
 and unwind_action = Continue | Unreachable | Terminate | Cleanup of int32
 and var_debug_info_content = Place of place | Const of const
 and var_debug_info_fragment = { ty : typ ; projection : projection list }
 and var_debug_info = { content : var_debug_info_content ; scope : int32 ; name : string ;
     arg_index : int32 option ; composite : var_debug_info_fragment option }
-and body = { stmts : basic_block list ; local_decls : local_decl list ;
+and user_self_ty = { impl_def_path : string ; self_ty : typ }
+and user_type_kind = Ty of typ | TypeOf of string * generic_arg list * user_self_ty option
+and user_type_annotation = { user_ty : user_type_kind ; span : span ; inferred_ty : typ }
+and unsafe_op_kind = DerefOfRawPointer | CallToUnsafeFunction of string option
+    | UseOfMutableStatic of string | UseOfExternStatic of string
+    | AccessToUnionField | UseOfInlineAssembly
+and unsafe_op = { kind : unsafe_op_kind ; block : int32 ; statement : int32 ;
+    span : span ; safety : safety_context }
+and hir_id = { owner : string ; local_id : int32 }
+and source_scope = { span : span ; parent : int32 option ; inlined : (instance * span) option ;
+    inlined_parent : int32 option ; lint_root : hir_id option }
+and def_kind = Fn | AssocFn | Closure | Const | AssocConst | Static of mutability
+    | AnonConst | InlineConst | Ctor | SyntheticCoroutineBody | Other of string
+and visibility = Public | Restricted of string
+and body_header = { def_path : string ; name : string option ; kind : def_kind ;
+    parent : string option ; visibility : visibility ; is_unsafe : bool ;
+    is_const : bool ; is_async : bool ; promoted : int32 option }
+and mir_phase = Built | Promoted | DropsElaborated | Optimized
+and body = { header : body_header ; phase : mir_phase ; stmts : basic_block list ; local_decls : local_decl list ;
     var_debug_info : var_debug_info list ; arg_count : int32 ;
-    spread_arg : int32 option ; span : span }
+    spread_arg : int32 option ; span : span ;
+    user_type_annotations : user_type_annotation list ;
+    source_scopes : source_scope list ; unsafe_ops : unsafe_op list ;
+    promoted : body list }
+and field_def = { name : string ; typ : typ }
+and variant_def = { name : string ; discr : string option ; fields : field_def list }
+and repr_options = { c : bool ; transparent : bool ; simd : bool ; linear : bool ;
+    int : typ option ; align : int32 option ; pack : int32 option }
+and adt_def = { path : string ; kind : adt_kind ; variants : variant_def list ;
+    repr : repr_options }
+and relocation = { offset : int32 ; target : int32 }
+and allocation = { bytes : int list ; align : int32 ; mutability : mutability ;
+    relocations : relocation list }
+and global_alloc = Memory of allocation | Function of instance | VTable of typ
+    | Static of string
+and alloc = { id : int32 ; kind : global_alloc }
+and source_file = { name : string ; start_pos : int32 ; end_pos : int32 ; src : string option }
+and crate_mir = { bodies : body list ; adts : adt_def list ; allocs : alloc list ;
+    source_files : source_file list }
+and crate_type = Bin | Lib | Rlib | Dylib | Cdylib | Staticlib | ProcMacro
+and edition = Edition2015 | Edition2018 | Edition2021 | Edition2024
+and config = { crate_type : crate_type ; edition : edition ; target : string option ;
+    crate_name : string option ; cfgs : string list ; externs : (string * string) list ;
+    search_paths : string list ; unstable_flags : string list ;
+    codegen_flags : string list ; phase : mir_phase }
+and workspace_crate = { package : string ; target : string ; crate_mir : crate_mir }
 
 
 (* file: lib.rs *)
 
-external get_mir: string -> body list = "get_mir"
+external get_mir: string -> (crate_mir, string) result = "get_mir"
+external get_mir_at_phase: string -> mir_phase -> (crate_mir, string) result = "get_mir_at_phase"
+external default_config: unit -> config = "default_config"
+external get_mir_with_config: string -> config -> (crate_mir, string) result = "get_mir_with_config"
+external get_workspace_mir: string -> config -> (workspace_crate list, string) result = "get_workspace_mir"
@@ -6,6 +6,11 @@ let rec pp_mutability (fmt : Format.formatter) (m : mutability) =
   | Mut -> Format.fprintf fmt "Mut"
   | Not -> Format.fprintf fmt "Not"
 
+and pp_safety (fmt : Format.formatter) (s : safety) =
+  match s with
+  | Safe -> Format.fprintf fmt "Safe"
+  | Unsafe -> Format.fprintf fmt "Unsafe"
+
 and pp_un_op (fmt : Format.formatter) (op : un_op) =
   match op with
   | Not -> Format.fprintf fmt "Not"
@@ -48,16 +53,75 @@ and pp_null_op (fmt : Format.formatter) (op : null_op) =
   | UbChecks -> Format.fprintf fmt "UbChecks"
   | Unknown -> Format.fprintf fmt "Unknown"
 
+and pp_pointer_coercion (fmt : Format.formatter) (pc : pointer_coercion) =
+  match pc with
+  | ReifyFnPointer -> Format.fprintf fmt "ReifyFnPointer"
+  | UnsafeFnPointer -> Format.fprintf fmt "UnsafeFnPointer"
+  | ClosureFnPointer s -> Format.fprintf fmt "ClosureFnPointer(%a)" pp_safety s
+  | MutToConstPointer -> Format.fprintf fmt "MutToConstPointer"
+  | ArrayToPointer -> Format.fprintf fmt "ArrayToPointer"
+  | Unsize -> Format.fprintf fmt "Unsize"
+
+and pp_coercion_source (fmt : Format.formatter) (cs : coercion_source) =
+  match cs with
+  | AsCast -> Format.fprintf fmt "AsCast"
+  | Implicit -> Format.fprintf fmt "Implicit"
+
+and pp_cast_kind (fmt : Format.formatter) (ck : cast_kind) =
+  match ck with
+  | PointerExposeProvenance -> Format.fprintf fmt "PointerExposeProvenance"
+  | PointerWithExposedProvenance -> Format.fprintf fmt "PointerWithExposedProvenance"
+  | PointerCoercion (pc, cs) ->
+      Format.fprintf fmt "PointerCoercion(%a, %a)" pp_pointer_coercion pc pp_coercion_source cs
+  | IntToInt -> Format.fprintf fmt "IntToInt"
+  | FloatToInt -> Format.fprintf fmt "FloatToInt"
+  | FloatToFloat -> Format.fprintf fmt "FloatToFloat"
+  | IntToFloat -> Format.fprintf fmt "IntToFloat"
+  | PtrToPtr -> Format.fprintf fmt "PtrToPtr"
+  | FnPtrToPtr -> Format.fprintf fmt "FnPtrToPtr"
+  | Transmute -> Format.fprintf fmt "Transmute"
+
+and pp_location (fmt : Format.formatter) (l : location) =
+  Format.fprintf fmt "%ld:%ld:%ld" l.file l.line l.col
+
+and pp_expansion_kind (fmt : Format.formatter) (k : expansion_kind) =
+  match k with
+  | Root -> Format.fprintf fmt "Root"
+  | MacroBang -> Format.fprintf fmt "MacroBang"
+  | MacroAttr -> Format.fprintf fmt "MacroAttr"
+  | MacroDerive -> Format.fprintf fmt "MacroDerive"
+  | AstPass -> Format.fprintf fmt "AstPass"
+  | Desugaring -> Format.fprintf fmt "Desugaring"
+
+and pp_expansion (fmt : Format.formatter) (e : expansion) =
+  Format.fprintf fmt "{ kind: %a; name: %s; call_site: %a }"
+    pp_expansion_kind e.kind e.name pp_span e.call_site
+
 and pp_span (fmt : Format.formatter) (sp : span) =
-  Format.fprintf fmt "(%ld, %ld)" (fst sp) (snd sp)
+  Format.fprintf fmt "{ lo: %ld; hi: %ld; start_loc: %a; end_loc: %a; from_expansion: %b; backtrace: [%a] }"
+    sp.lo sp.hi pp_location sp.start_loc pp_location sp.end_loc
+    sp.from_expansion (Format.pp_print_list pp_expansion) sp.backtrace
 
 and pp_borrow_kind (fmt : Format.formatter) (bk : borrow_kind) =
   match bk with
   | Shared -> Format.fprintf fmt "Shared"
-  | Fake -> Format.fprintf fmt "Fake"
-  | Mut -> Format.fprintf fmt "Mut"
+  | Fake k ->
+      Format.fprintf fmt "Fake(%s)"
+        (match (k : fake_borrow_kind) with Shallow -> "Shallow" | Deep -> "Deep")
+  | Mut k ->
+      Format.fprintf fmt "Mut(%s)"
+        (match (k : mut_borrow_kind) with
+         | Default -> "Default"
+         | TwoPhaseBorrow -> "TwoPhaseBorrow"
+         | ClosureCapture -> "ClosureCapture")
   | Unknown -> Format.fprintf fmt "Unknown"
 
+and pp_region (fmt : Format.formatter) (r : region) =
+  match r with
+  | Static -> Format.fprintf fmt "Static"
+  | Erased -> Format.fprintf fmt "Erased"
+  | Other r -> Format.fprintf fmt "Other(%s)" r
+
 and pp_rkind (fmt : Format.formatter) (rk : rkind) =
   match rk with
   | FnEntry -> Format.fprintf fmt "FnEntry"
@@ -75,15 +139,71 @@ and pp_expr_kind (fmt : Format.formatter) (ek : expr_kind) =
 
 and pp_const_val (fmt : Format.formatter) (cv : const_val) =
   match cv with
-  | ScalarInt i -> Format.fprintf fmt "ScalarInt(%ld)" i
+  | ScalarInt (bits, size) -> Format.fprintf fmt "ScalarInt(%s, %d)" bits size
   | ScalarPtr (addr, offset, size) ->
       Format.fprintf fmt "ScalarPtr(%ld, %ld, %d)" addr offset size
   | ZeroSized -> Format.fprintf fmt "ZeroSized"
-  | Slice (len, mut) ->
-      Format.fprintf fmt "Slice(%ld, %a)" len pp_mutability mut
+  | Slice (id, len, mut) ->
+      Format.fprintf fmt "Slice(%ld, %ld, %a)" id len pp_mutability mut
   | Indirect (addr, size) ->
       Format.fprintf fmt "Indirect(%ld, %ld)" addr size
 
+and pp_adt_kind (fmt : Format.formatter) (ak : adt_kind) =
+  match ak with
+  | Struct -> Format.fprintf fmt "Struct"
+  | Enum -> Format.fprintf fmt "Enum"
+  | Union -> Format.fprintf fmt "Union"
+
+and pp_fn_sig (fmt : Format.formatter) (fs : fn_sig) =
+  Format.fprintf fmt "{ inputs: [%a]; output: %a; abi: %s; safety: %a; c_variadic: %b }"
+    (Format.pp_print_list pp_typ) fs.inputs pp_typ fs.output
+    fs.abi pp_safety fs.safety fs.c_variadic
+
+and pp_closure_kind (fmt : Format.formatter) (ck : closure_kind) =
+  match ck with
+  | Fn -> Format.fprintf fmt "Fn"
+  | FnMut -> Format.fprintf fmt "FnMut"
+  | FnOnce -> Format.fprintf fmt "FnOnce"
+
+and pp_capture_kind (fmt : Format.formatter) (ck : capture_kind) =
+  match ck with
+  | ByValue -> Format.fprintf fmt "ByValue"
+  | ByUse -> Format.fprintf fmt "ByUse"
+  | ByRef -> Format.fprintf fmt "ByRef"
+  | ByUniqueRef -> Format.fprintf fmt "ByUniqueRef"
+  | ByMutRef -> Format.fprintf fmt "ByMutRef"
+  | Unknown -> Format.fprintf fmt "Unknown"
+
+and pp_upvar (fmt : Format.formatter) (u : upvar) =
+  Format.fprintf fmt "{ name: %s; typ: %a; capture: %a }"
+    u.name pp_typ u.typ pp_capture_kind u.capture
+
+and pp_coroutine_sig (fmt : Format.formatter) (cs : coroutine_sig) =
+  Format.fprintf fmt "{ resume_ty: %a; yield_ty: %a; return_ty: %a }"
+    pp_typ cs.resume_ty pp_typ cs.yield_ty pp_typ cs.return_ty
+
+and pp_alias_kind (fmt : Format.formatter) (ak : alias_kind) =
+  match ak with
+  | Projection -> Format.fprintf fmt "Projection"
+  | Inherent -> Format.fprintf fmt "Inherent"
+  | Opaque -> Format.fprintf fmt "Opaque"
+  | Free -> Format.fprintf fmt "Free"
+
+and pp_trait_ref (fmt : Format.formatter) (tr : trait_ref) =
+  Format.fprintf fmt "{ path: %s; args: [%a] }"
+    tr.path (Format.pp_print_list pp_generic_arg) tr.args
+
+and pp_dyn_bound (fmt : Format.formatter) (db : dyn_bound) =
+  Format.fprintf fmt "{ principal: %a; auto_traits: [%a]; region: %s }"
+    (Format.pp_print_option pp_trait_ref) db.principal
+    (Format.pp_print_list Format.pp_print_string) db.auto_traits
+    db.region
+
+and pp_ty_pattern (fmt : Format.formatter) (p : ty_pattern) =
+  match p with
+  | Range (s, e) -> Format.fprintf fmt "Range(%a, %a)" pp_const s pp_const e
+  | Or pats -> Format.fprintf fmt "Or([%a])" (Format.pp_print_list pp_ty_pattern) pats
+
 and pp_typ (fmt : Format.formatter) (ty : typ) =
   match ty with
   | Bool -> Format.fprintf fmt "Bool"
@@ -103,8 +223,39 @@ and pp_typ (fmt : Format.formatter) (ty : typ) =
       Format.fprintf fmt "Ref(%a, %a)" pp_typ t pp_mutability m
   | Tuple ts ->
       Format.fprintf fmt "Tuple([%a])" (Format.pp_print_list pp_typ) ts
+  | Adt (path, kind, args) ->
+      Format.fprintf fmt "Adt(%s, %a, [%a])" path pp_adt_kind kind
+        (Format.pp_print_list pp_generic_arg) args
+  | FnDef (path, args) ->
+      Format.fprintf fmt "FnDef(%s, [%a])" path (Format.pp_print_list pp_generic_arg) args
+  | FnPtr fs -> Format.fprintf fmt "FnPtr(%a)" pp_fn_sig fs
+  | Closure (path, kind, upvars) ->
+      Format.fprintf fmt "Closure(%s, %a, [%a])" path pp_closure_kind kind
+        (Format.pp_print_list pp_upvar) upvars
+  | Coroutine (path, upvars, cs) ->
+      Format.fprintf fmt "Coroutine(%s, [%a], %a)" path
+        (Format.pp_print_list pp_upvar) upvars pp_coroutine_sig cs
+  | CoroutineClosure (path, kind, upvars) ->
+      Format.fprintf fmt "CoroutineClosure(%s, %a, [%a])" path pp_closure_kind kind
+        (Format.pp_print_list pp_upvar) upvars
+  | Never -> Format.fprintf fmt "Never"
+  | Param (i, name) -> Format.fprintf fmt "Param(%ld, %s)" i name
+  | Foreign path -> Format.fprintf fmt "Foreign(%s)" path
+  | Pat (t, pat) -> Format.fprintf fmt "Pat(%a, %a)" pp_typ t pp_ty_pattern pat
+  | UnsafeBinder t -> Format.fprintf fmt "UnsafeBinder(%a)" pp_typ t
+  | Alias (kind, path, args) ->
+      Format.fprintf fmt "Alias(%a, %s, [%a])" pp_alias_kind kind path
+        (Format.pp_print_list pp_generic_arg) args
+  | Dynamic db -> Format.fprintf fmt "Dynamic(%a)" pp_dyn_bound db
   | Unknown -> Format.fprintf fmt "Unknown"
 
+and pp_unevaluated_const (fmt : Format.formatter) (uc : unevaluated_const) =
+  Format.fprintf fmt "{ def_path: %s; args: [%a]; promoted: %a; value: %a; typ: %a }"
+    uc.def_path (Format.pp_print_list pp_generic_arg) uc.args
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int uc.promoted)
+    (Format.pp_print_option pp_const_val) uc.value
+    pp_typ uc.typ
+
 and pp_const (fmt : Format.formatter) (c : const) =
   match c with
   | Ty (t, c) -> Format.fprintf fmt "Ty(%a, %a)" pp_typ t pp_const c
@@ -113,12 +264,19 @@ and pp_const (fmt : Format.formatter) (c : const) =
   | Expr (ek, args) ->
       Format.fprintf fmt "Expr(%a, [%a])" pp_expr_kind ek
         (Format.pp_print_list pp_arg) args
+  | Unevaluated uc -> Format.fprintf fmt "Unevaluated(%a)" pp_unevaluated_const uc
   | Unknown -> Format.fprintf fmt "Unknown"
 
 and pp_arg (fmt : Format.formatter) (a : arg) =
   match a with
   | (t, c) -> Format.fprintf fmt "%a %a" pp_typ t pp_const c
 
+and pp_generic_arg (fmt : Format.formatter) (ga : generic_arg) =
+  match ga with
+  | Type t -> Format.fprintf fmt "Type(%a)" pp_typ t
+  | Const c -> Format.fprintf fmt "Const(%a)" pp_const c
+  | Lifetime -> Format.fprintf fmt "Lifetime"
+
 and pp_projection (fmt : Format.formatter) (p : projection) =
   match p with
   | Deref -> Format.fprintf fmt "Deref"
@@ -133,10 +291,37 @@ and pp_projection (fmt : Format.formatter) (p : projection) =
   | Subtype t -> Format.fprintf fmt "Subtype(%a)" pp_typ t
   | UnwrapUnsafeBinder t -> Format.fprintf fmt "UnwrapUnsafeBinder(%a)" pp_typ t
 
+and pp_projection_kind (fmt : Format.formatter) (p : projection_kind) =
+  match p with
+  | Deref -> Format.fprintf fmt "Deref"
+  | Field i -> Format.fprintf fmt "Field(%ld)" i
+  | Index -> Format.fprintf fmt "Index"
+  | ConstantIndex (i, len, is_slice) ->
+      Format.fprintf fmt "ConstantIndex(%ld, %ld, %b)" i len is_slice
+  | Subslice (start, end_, is_slice) ->
+      Format.fprintf fmt "Subslice(%ld, %ld, %b)" start end_ is_slice
+  | Downcast i -> Format.fprintf fmt "Downcast(%ld)" i
+  | OpaqueCast -> Format.fprintf fmt "OpaqueCast"
+  | Subtype -> Format.fprintf fmt "Subtype"
+  | UnwrapUnsafeBinder -> Format.fprintf fmt "UnwrapUnsafeBinder"
+
 and pp_place (fmt : Format.formatter) (p : place) =
   Format.fprintf fmt "Place { local: %ld; proj: [%a] }" p.local
     (Format.pp_print_list pp_projection) p.proj
 
+and pp_var_binding (fmt : Format.formatter) (vb : var_binding) =
+  Format.fprintf fmt "{ by_ref: %a; mutability: %a; opt_ty_info: %a; pat_span: %a }"
+    (Format.pp_print_option pp_mutability) vb.by_ref pp_mutability vb.mutability
+    (Format.pp_print_option pp_span) vb.opt_ty_info pp_span vb.pat_span
+
+and pp_implicit_self_kind (fmt : Format.formatter) (k : implicit_self_kind) =
+  match k with
+  | Imm -> Format.fprintf fmt "Imm"
+  | Mut -> Format.fprintf fmt "Mut"
+  | RefImm -> Format.fprintf fmt "RefImm"
+  | RefMut -> Format.fprintf fmt "RefMut"
+  | NoSelf -> Format.fprintf fmt "NoSelf"
+
 and pp_local_info (fmt : Format.formatter) (li : local_info) =
   match li with
   | ConstRef i -> Format.fprintf fmt "ConstRef(%ld)" i
@@ -145,11 +330,15 @@ and pp_local_info (fmt : Format.formatter) (li : local_info) =
   | FakeBorrow -> Format.fprintf fmt "FakeBorrow"
   | Boring -> Format.fprintf fmt "Boring"
   | StaticRef i -> Format.fprintf fmt "StaticRef(%ld)" i
+  | User vb -> Format.fprintf fmt "User(%a)" pp_var_binding vb
+  | ImplicitSelf k -> Format.fprintf fmt "ImplicitSelf(%a)" pp_implicit_self_kind k
+  | RefForGuard -> Format.fprintf fmt "RefForGuard"
   | Unknown -> Format.fprintf fmt "Unknown"
 
   and pp_local_decl (fmt : Format.formatter) (ld : local_decl) =
-  Format.fprintf fmt "LocalDecl { scope: %ld; local: %a; typ: %a; mut: %a }"
+  Format.fprintf fmt "LocalDecl { scope: %ld; local: %a; typ: %a; mut: %a; span: %a; user_ty: [%a] }"
     ld.scope pp_local_info ld.local pp_typ ld.typ pp_mutability ld.mut
+    pp_span ld.span (Format.pp_print_list pp_user_type_projection) ld.user_ty
 
 and pp_operand (fmt : Format.formatter) (op : operand) =
   match op with
@@ -164,11 +353,29 @@ and pp_intrinsic (fmt : Format.formatter) (i : intrinsic) =
       Format.fprintf fmt "CopyNonOverlapping(%a, %a, %a)"
         pp_operand src pp_operand dst pp_operand len
 
+and pp_aggregate_kind (fmt : Format.formatter) (ak : aggregate_kind) =
+  match ak with
+  | Array t -> Format.fprintf fmt "Array(%a)" pp_typ t
+  | Tuple -> Format.fprintf fmt "Tuple"
+  | Adt (path, variant, args, active_field) ->
+      Format.fprintf fmt "Adt(%s, %ld, [%a], %a)" path variant
+        (Format.pp_print_list pp_generic_arg) args
+        (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int active_field)
+  | Closure (path, args) ->
+      Format.fprintf fmt "Closure(%s, [%a])" path (Format.pp_print_list pp_generic_arg) args
+  | Coroutine (path, args) ->
+      Format.fprintf fmt "Coroutine(%s, [%a])" path (Format.pp_print_list pp_generic_arg) args
+  | CoroutineClosure (path, args) ->
+      Format.fprintf fmt "CoroutineClosure(%s, [%a])" path
+        (Format.pp_print_list pp_generic_arg) args
+  | RawPtr (t, m) -> Format.fprintf fmt "RawPtr(%a, %a)" pp_typ t pp_mutability m
+
 and pp_rvalue (fmt : Format.formatter) (rv : rvalue) =
   match rv with
   | Use op -> Format.fprintf fmt "Use(%a)" pp_operand op
   | Repeat (op, c) -> Format.fprintf fmt "Repeat(%a, %a)" pp_operand op pp_const c
-  | Ref (bk, p) -> Format.fprintf fmt "Ref(%a, %a)" pp_borrow_kind bk pp_place p
+  | Ref (r, bk, p) ->
+      Format.fprintf fmt "Ref(%a, %a, %a)" pp_region r pp_borrow_kind bk pp_place p
   | RawPtr (m, p) -> Format.fprintf fmt "RawPtr(%a, %a)" pp_mutability m pp_place p
   | Len p -> Format.fprintf fmt "Len(%a)" pp_place p
   | BinaryOp (op, lhs, rhs) ->
@@ -181,8 +388,47 @@ and pp_rvalue (fmt : Format.formatter) (rv : rvalue) =
   | CopyForDeref p -> Format.fprintf fmt "CopyForDeref(%a)" pp_place p
   | WrapUnsafeBinder (op, t) ->
       Format.fprintf fmt "WrapUnsafeBinder(%a, %a)" pp_operand op pp_typ t
+  | Aggregate (ak, ops) ->
+      Format.fprintf fmt "Aggregate(%a, [%a])" pp_aggregate_kind ak
+        (Format.pp_print_list pp_operand) ops
+  | Cast (ck, op, t) ->
+      Format.fprintf fmt "Cast(%a, %a, %a)" pp_cast_kind ck pp_operand op pp_typ t
   | Unknown -> Format.fprintf fmt "Unknown"
 
+and pp_fake_read_cause (fmt : Format.formatter) (c : fake_read_cause) =
+  match c with
+  | ForMatchGuard -> Format.fprintf fmt "ForMatchGuard"
+  | ForMatchedPlace closure ->
+      Format.fprintf fmt "ForMatchedPlace(%a)" (Format.pp_print_option Format.pp_print_string) closure
+  | ForGuardBinding -> Format.fprintf fmt "ForGuardBinding"
+  | ForLet closure ->
+      Format.fprintf fmt "ForLet(%a)" (Format.pp_print_option Format.pp_print_string) closure
+  | ForIndex -> Format.fprintf fmt "ForIndex"
+
+and pp_variance (fmt : Format.formatter) (v : variance) =
+  match v with
+  | Covariant -> Format.fprintf fmt "Covariant"
+  | Invariant -> Format.fprintf fmt "Invariant"
+  | Contravariant -> Format.fprintf fmt "Contravariant"
+  | Bivariant -> Format.fprintf fmt "Bivariant"
+
+and pp_coverage_kind (fmt : Format.formatter) (k : coverage_kind) =
+  match k with
+  | SpanMarker -> Format.fprintf fmt "SpanMarker"
+  | BlockMarker id -> Format.fprintf fmt "BlockMarker(%ld)" id
+  | VirtualCounter bcb -> Format.fprintf fmt "VirtualCounter(%ld)" bcb
+  | CondBitmapUpdate (index, depth) -> Format.fprintf fmt "CondBitmapUpdate(%ld, %ld)" index depth
+  | TestVectorBitmapUpdate (index, depth) ->
+      Format.fprintf fmt "TestVectorBitmapUpdate(%ld, %ld)" index depth
+
+and pp_drop_hint_reason (fmt : Format.formatter) (r : drop_hint_reason) =
+  match r with
+  | Edition2024 -> Format.fprintf fmt "Edition2024"
+
+and pp_user_type_projection (fmt : Format.formatter) (p : user_type_projection) =
+  Format.fprintf fmt "{ base: %ld; projs: [%a] }"
+    p.base (Format.pp_print_list pp_projection_kind) p.projs
+
 and pp_statment_kind (fmt : Format.formatter) (sk : statement_kind) =
   match sk with
   | Assign (p, rv) -> Format.fprintf fmt "Assign(%a, %a)" pp_place p pp_rvalue rv
@@ -207,26 +453,95 @@ and pp_statment_kind (fmt : Format.formatter) (sk : statement_kind) =
   | Call ci -> Format.fprintf fmt "Call(%a)" pp_call_info ci
   | Assert ai -> Format.fprintf fmt "Assert(%a)" pp_assert_info ai
   | CoroutineDrop -> Format.fprintf fmt "CoroutineDrop"
+  | Yield yi -> Format.fprintf fmt "Yield(%a)" pp_yield_info yi
+  | FalseEdge (real, imaginary) ->
+      Format.fprintf fmt "FalseEdge(%ld, %ld)" real imaginary
+  | FalseUnwind (real, unwind) ->
+      Format.fprintf fmt "FalseUnwind(%ld, %a)" real pp_unwind_action unwind
+  | InlineAsm ia -> Format.fprintf fmt "InlineAsm(%a)" pp_inline_asm_info ia
+  | TailCall (func, args) ->
+      Format.fprintf fmt "TailCall(%a, [%a])" pp_operand func
+        (Format.pp_print_list pp_operand) args
+  | FakeRead (cause, p) ->
+      Format.fprintf fmt "FakeRead(%a, %a)" pp_fake_read_cause cause pp_place p
+  | AscribeUserType (p, proj, v) ->
+      Format.fprintf fmt "AscribeUserType(%a, %a, %a)" pp_place p
+        pp_user_type_projection proj pp_variance v
+  | Coverage kind -> Format.fprintf fmt "Coverage(%a)" pp_coverage_kind kind
+  | BackwardIncompatibleDropHint (p, reason) ->
+      Format.fprintf fmt "BackwardIncompatibleDropHint(%a, %a)" pp_place p pp_drop_hint_reason reason
+  | Unknown -> Format.fprintf fmt "Unknown"
+
+and pp_safety_context (fmt : Format.formatter) (sc : safety_context) =
+  match sc with
+  | Safe -> Format.fprintf fmt "Safe"
+  | UnsafeBlock -> Format.fprintf fmt "UnsafeBlock"
+  | UnsafeFn -> Format.fprintf fmt "UnsafeFn"
+  | BuiltinUnsafe -> Format.fprintf fmt "BuiltinUnsafe"
   | Unknown -> Format.fprintf fmt "Unknown"
 
 and pp_statement (fmt : Format.formatter) (s : statement) =
-  Format.fprintf fmt "{ skind: %a; span: %a; scope: %ld }"
-    pp_statment_kind s.skind pp_span s.span s.scope
+  Format.fprintf fmt "{ skind: %a; span: %a; scope: %ld; safety: %a }"
+    pp_statment_kind s.skind pp_span s.span s.scope pp_safety_context s.safety
 
 and pp_targets (fmt : Format.formatter) (t : targets) =
   let int32_to_int_list l = List.map Int32.to_int l in
-  Format.fprintf fmt "{ targets: [%a]; values: [%a] }"
+  Format.fprintf fmt "{ targets: [%a]; values: [%a]; otherwise: %ld }"
     (Format.pp_print_list Format.pp_print_int) (int32_to_int_list t.targets)
-    (Format.pp_print_list Format.pp_print_int) (int32_to_int_list t.values)
+    (Format.pp_print_list Format.pp_print_string) t.values
+    t.otherwise
+
+and pp_instance_kind (fmt : Format.formatter) (ik : instance_kind) =
+  match ik with
+  | Item -> Format.fprintf fmt "Item"
+  | Intrinsic -> Format.fprintf fmt "Intrinsic"
+  | VTableShim -> Format.fprintf fmt "VTableShim"
+  | ReifyShim -> Format.fprintf fmt "ReifyShim"
+  | FnPtrShim t -> Format.fprintf fmt "FnPtrShim(%a)" pp_typ t
+  | Virtual i -> Format.fprintf fmt "Virtual(%ld)" i
+  | ClosureOnceShim -> Format.fprintf fmt "ClosureOnceShim"
+  | ConstructCoroutineInClosureShim -> Format.fprintf fmt "ConstructCoroutineInClosureShim"
+  | ThreadLocalShim -> Format.fprintf fmt "ThreadLocalShim"
+  | DropGlue t -> Format.fprintf fmt "DropGlue(%a)" (Format.pp_print_option pp_typ) t
+  | CloneShim t -> Format.fprintf fmt "CloneShim(%a)" pp_typ t
+  | FnPtrAddrShim t -> Format.fprintf fmt "FnPtrAddrShim(%a)" pp_typ t
+  | FutureDropPollShim (p, t) -> Format.fprintf fmt "FutureDropPollShim(%a, %a)" pp_typ p pp_typ t
+  | AsyncDropGlueCtorShim t -> Format.fprintf fmt "AsyncDropGlueCtorShim(%a)" pp_typ t
+  | AsyncDropGlue t -> Format.fprintf fmt "AsyncDropGlue(%a)" pp_typ t
+
+and pp_instance (fmt : Format.formatter) (i : instance) =
+  Format.fprintf fmt "{ def_path: %s; kind: %a; args: [%a] }"
+    i.def_path pp_instance_kind i.kind
+    (Format.pp_print_list pp_generic_arg) i.args
+
+and pp_call_target (fmt : Format.formatter) (ct : call_target) =
+  match ct with
+  | Resolved i -> Format.fprintf fmt "Resolved(%a)" pp_instance i
+  | TooGeneric -> Format.fprintf fmt "TooGeneric"
+  | Indirect -> Format.fprintf fmt "Indirect"
+  | Failed -> Format.fprintf fmt "Failed"
 
 and pp_call_info (fmt : Format.formatter) (ci : call_info) =
-  Format.fprintf fmt "{ func: %a; args: [%a]; dest: %a; target: %a; unwind: %a; span: %a }"
+  Format.fprintf fmt "{ func: %a; args: [%a]; dest: %a; target: %a; unwind: %a; span: %a; resolved: %a }"
     pp_operand ci.func
     (Format.pp_print_list pp_operand) ci.args
     pp_place ci.dest
     (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int ci.target)
     pp_unwind_action ci.unwind
     pp_span ci.span
+    pp_call_target ci.resolved
+
+and pp_coroutine_kind (fmt : Format.formatter) (k : coroutine_kind) =
+  match k with
+  | Desugared (d, s) ->
+      let desugaring = match (d : coroutine_desugaring) with
+        | Async -> "Async" | Gen -> "Gen" | AsyncGen -> "AsyncGen" in
+      let source = match (s : coroutine_source) with
+        | Block -> "Block" | Closure -> "Closure" | Fn -> "Fn" in
+      Format.fprintf fmt "Desugared(%s, %s)" desugaring source
+  | Coroutine m ->
+      Format.fprintf fmt "Coroutine(%s)"
+        (match (m : movability) with Static -> "Static" | Movable -> "Movable")
 
 and pp_assert_message (fmt : Format.formatter) (am : assert_message) =
   match am with
@@ -240,6 +555,11 @@ and pp_assert_message (fmt : Format.formatter) (am : assert_message) =
   | MisalignedPointerDereference (op1, op2) ->
       Format.fprintf fmt "MisalignedPointerDereference(%a, %a)" pp_operand op1 pp_operand op2
   | NullPointerDereference -> Format.fprintf fmt "NullPointerDereference"
+  | ResumedAfterReturn k -> Format.fprintf fmt "ResumedAfterReturn(%a)" pp_coroutine_kind k
+  | ResumedAfterPanic k -> Format.fprintf fmt "ResumedAfterPanic(%a)" pp_coroutine_kind k
+  | ResumedAfterDrop k -> Format.fprintf fmt "ResumedAfterDrop(%a)" pp_coroutine_kind k
+  | InvalidEnumConstruction op ->
+      Format.fprintf fmt "InvalidEnumConstruction(%a)" pp_operand op
   | Unknown -> Format.fprintf fmt "Unknown"
 
 and pp_drop_info (fmt : Format.formatter) (di : drop_info) =
@@ -249,10 +569,35 @@ and pp_drop_info (fmt : Format.formatter) (di : drop_info) =
     (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int di.drop)
 
 and pp_assert_info (fmt : Format.formatter) (ai : assert_info) =
-  Format.fprintf fmt "{ cond: %a; expected: %b; msg: %s; target: %ld; unwind: %a }"
-    pp_operand ai.cond ai.expected ai.msg ai.target
+  Format.fprintf fmt "{ cond: %a; expected: %b; msg: %a; target: %ld; unwind: %a }"
+    pp_operand ai.cond ai.expected pp_assert_message ai.msg ai.target
     pp_unwind_action ai.unwind
 
+and pp_yield_info (fmt : Format.formatter) (yi : yield_info) =
+  Format.fprintf fmt "{ value: %a; resume: %ld; resume_arg: %a; drop: %a }"
+    pp_operand yi.value yi.resume pp_place yi.resume_arg
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int yi.drop)
+
+and pp_asm_operand (fmt : Format.formatter) (ao : asm_operand) =
+  match ao with
+  | In (reg, op) -> Format.fprintf fmt "In(%s, %a)" reg pp_operand op
+  | Out (reg, late, p) ->
+      Format.fprintf fmt "Out(%s, %b, %a)" reg late (Format.pp_print_option pp_place) p
+  | InOut (reg, late, op, p) ->
+      Format.fprintf fmt "InOut(%s, %b, %a, %a)" reg late pp_operand op
+        (Format.pp_print_option pp_place) p
+  | Const c -> Format.fprintf fmt "Const(%a)" pp_const c
+  | SymFn c -> Format.fprintf fmt "SymFn(%a)" pp_const c
+  | SymStatic path -> Format.fprintf fmt "SymStatic(%s)" path
+  | Label target -> Format.fprintf fmt "Label(%ld)" target
+
+and pp_inline_asm_info (fmt : Format.formatter) (ia : inline_asm_info) =
+  Format.fprintf fmt "{ template: %S; operands: [%a]; options: [%a]; targets: [%a]; unwind: %a }"
+    ia.template (Format.pp_print_list pp_asm_operand) ia.operands
+    (Format.pp_print_list Format.pp_print_string) ia.options
+    (Format.pp_print_list Format.pp_print_int) (List.map Int32.to_int ia.targets)
+    pp_unwind_action ia.unwind
+
 and pp_basic_block (fmt : Format.formatter) (bb : basic_block) =
   Format.fprintf fmt "{ statements: [%a]; is_cleanup: %b }"
     (Format.pp_print_list pp_statement) bb.statements bb.is_cleanup
@@ -279,11 +624,178 @@ and pp_var_debug_info_fragment (fmt : Format.formatter) (v : var_debug_info_frag
   Format.fprintf fmt "{ ty: %a; projection: [%a] }"
     pp_typ v.ty (Format.pp_print_list pp_projection) v.projection
 
+and pp_user_self_ty (fmt : Format.formatter) (s : user_self_ty) =
+  Format.fprintf fmt "{ impl_def_path: %s; self_ty: %a }" s.impl_def_path pp_typ s.self_ty
+
+and pp_user_type_kind (fmt : Format.formatter) (k : user_type_kind) =
+  match k with
+  | Ty t -> Format.fprintf fmt "Ty(%a)" pp_typ t
+  | TypeOf (path, args, self_ty) ->
+      Format.fprintf fmt "TypeOf(%s, [%a], %a)" path
+        (Format.pp_print_list pp_generic_arg) args
+        (Format.pp_print_option pp_user_self_ty) self_ty
+
+and pp_user_type_annotation (fmt : Format.formatter) (a : user_type_annotation) =
+  Format.fprintf fmt "{ user_ty: %a; span: %a; inferred_ty: %a }"
+    pp_user_type_kind a.user_ty pp_span a.span pp_typ a.inferred_ty
+
+and pp_unsafe_op_kind (fmt : Format.formatter) (k : unsafe_op_kind) =
+  match k with
+  | DerefOfRawPointer -> Format.fprintf fmt "DerefOfRawPointer"
+  | CallToUnsafeFunction callee ->
+      Format.fprintf fmt "CallToUnsafeFunction(%a)" (Format.pp_print_option Format.pp_print_string) callee
+  | UseOfMutableStatic path -> Format.fprintf fmt "UseOfMutableStatic(%s)" path
+  | UseOfExternStatic path -> Format.fprintf fmt "UseOfExternStatic(%s)" path
+  | AccessToUnionField -> Format.fprintf fmt "AccessToUnionField"
+  | UseOfInlineAssembly -> Format.fprintf fmt "UseOfInlineAssembly"
+
+and pp_unsafe_op (fmt : Format.formatter) (op : unsafe_op) =
+  Format.fprintf fmt "{ kind: %a; block: %ld; statement: %ld; span: %a; safety: %a }"
+    pp_unsafe_op_kind op.kind op.block op.statement pp_span op.span
+    pp_safety_context op.safety
+
+and pp_hir_id (fmt : Format.formatter) (h : hir_id) =
+  Format.fprintf fmt "%s#%ld" h.owner h.local_id
+
+and pp_source_scope (fmt : Format.formatter) (s : source_scope) =
+  let pp_inlined fmt (i, call_site) =
+    Format.fprintf fmt "(%a, %a)" pp_instance i pp_span call_site in
+  Format.fprintf fmt "{ span: %a; parent: %a; inlined: %a; inlined_parent: %a; lint_root: %a }"
+    pp_span s.span
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int s.parent)
+    (Format.pp_print_option pp_inlined) s.inlined
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int s.inlined_parent)
+    (Format.pp_print_option pp_hir_id) s.lint_root
+
+and pp_def_kind (fmt : Format.formatter) (k : def_kind) =
+  match k with
+  | Fn -> Format.fprintf fmt "Fn"
+  | AssocFn -> Format.fprintf fmt "AssocFn"
+  | Closure -> Format.fprintf fmt "Closure"
+  | Const -> Format.fprintf fmt "Const"
+  | AssocConst -> Format.fprintf fmt "AssocConst"
+  | Static m -> Format.fprintf fmt "Static(%a)" pp_mutability m
+  | AnonConst -> Format.fprintf fmt "AnonConst"
+  | InlineConst -> Format.fprintf fmt "InlineConst"
+  | Ctor -> Format.fprintf fmt "Ctor"
+  | SyntheticCoroutineBody -> Format.fprintf fmt "SyntheticCoroutineBody"
+  | Other descr -> Format.fprintf fmt "Other(%s)" descr
+
+and pp_visibility (fmt : Format.formatter) (v : visibility) =
+  match v with
+  | Public -> Format.fprintf fmt "Public"
+  | Restricted m -> Format.fprintf fmt "Restricted(%s)" m
+
+and pp_body_header (fmt : Format.formatter) (h : body_header) =
+  Format.fprintf fmt "{ def_path: %s; name: %a; kind: %a; parent: %a; visibility: %a; is_unsafe: %b; is_const: %b; is_async: %b; promoted: %a }"
+    h.def_path (Format.pp_print_option Format.pp_print_string) h.name
+    pp_def_kind h.kind
+    (Format.pp_print_option Format.pp_print_string) h.parent
+    pp_visibility h.visibility h.is_unsafe h.is_const h.is_async
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int h.promoted)
+
+and pp_mir_phase (fmt : Format.formatter) (p : mir_phase) =
+  match p with
+  | Built -> Format.fprintf fmt "Built"
+  | Promoted -> Format.fprintf fmt "Promoted"
+  | DropsElaborated -> Format.fprintf fmt "DropsElaborated"
+  | Optimized -> Format.fprintf fmt "Optimized"
+
 and pp_body (fmt : Format.formatter) (b : body) =
-  Format.fprintf fmt "{ stmts: [%a]; local_decls: [%a]; var_debug_info: [%a]; arg_count: %ld; spread_arg: %a; span: %a }"
+  Format.fprintf fmt "{ header: %a; phase: %a; stmts: [%a]; local_decls: [%a]; var_debug_info: [%a]; arg_count: %ld; spread_arg: %a; span: %a; user_type_annotations: [%a]; source_scopes: [%a]; unsafe_ops: [%a]; promoted: [%a] }"
+    pp_body_header b.header pp_mir_phase b.phase
     (Format.pp_print_list pp_basic_block) b.stmts
     (Format.pp_print_list pp_local_decl) b.local_decls
     (Format.pp_print_list pp_var_debug_info) b.var_debug_info
     b.arg_count
     (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int b.spread_arg)
     pp_span b.span
+    (Format.pp_print_list pp_user_type_annotation) b.user_type_annotations
+    (Format.pp_print_list pp_source_scope) b.source_scopes
+    (Format.pp_print_list pp_unsafe_op) b.unsafe_ops
+    (Format.pp_print_list pp_body) b.promoted
+
+
+and pp_field_def (fmt : Format.formatter) (f : field_def) =
+  Format.fprintf fmt "{ name: %s; typ: %a }" f.name pp_typ f.typ
+
+and pp_variant_def (fmt : Format.formatter) (v : variant_def) =
+  Format.fprintf fmt "{ name: %s; discr: %a; fields: [%a] }" v.name
+    (Format.pp_print_option Format.pp_print_string) v.discr
+    (Format.pp_print_list pp_field_def) v.fields
+
+and pp_repr_options (fmt : Format.formatter) (r : repr_options) =
+  Format.fprintf fmt "{ c: %b; transparent: %b; simd: %b; linear: %b; int: %a; align: %a; pack: %a }"
+    r.c r.transparent r.simd r.linear
+    (Format.pp_print_option pp_typ) r.int
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int r.align)
+    (Format.pp_print_option Format.pp_print_int) (Option.map Int32.to_int r.pack)
+
+and pp_adt_def (fmt : Format.formatter) (a : adt_def) =
+  Format.fprintf fmt "{ path: %s; kind: %a; variants: [%a]; repr: %a }"
+    a.path pp_adt_kind a.kind
+    (Format.pp_print_list pp_variant_def) a.variants
+    pp_repr_options a.repr
+
+and pp_relocation (fmt : Format.formatter) (r : relocation) =
+  Format.fprintf fmt "{ offset: %ld; target: %ld }" r.offset r.target
+
+and pp_allocation (fmt : Format.formatter) (a : allocation) =
+  Format.fprintf fmt "{ bytes: [%a]; align: %ld; mutability: %a; relocations: [%a] }"
+    (Format.pp_print_list Format.pp_print_int) a.bytes
+    a.align pp_mutability a.mutability
+    (Format.pp_print_list pp_relocation) a.relocations
+
+and pp_global_alloc (fmt : Format.formatter) (ga : global_alloc) =
+  match ga with
+  | Memory a -> Format.fprintf fmt "Memory(%a)" pp_allocation a
+  | Function i -> Format.fprintf fmt "Function(%a)" pp_instance i
+  | VTable t -> Format.fprintf fmt "VTable(%a)" pp_typ t
+  | Static path -> Format.fprintf fmt "Static(%s)" path
+
+and pp_alloc (fmt : Format.formatter) (a : alloc) =
+  Format.fprintf fmt "{ id: %ld; kind: %a }" a.id pp_global_alloc a.kind
+
+and pp_source_file (fmt : Format.formatter) (f : source_file) =
+  Format.fprintf fmt "{ name: %s; start_pos: %ld; end_pos: %ld; src: %s }"
+    f.name f.start_pos f.end_pos
+    (match f.src with Some _ -> "<loaded>" | None -> "<none>")
+
+and pp_crate_mir (fmt : Format.formatter) (c : crate_mir) =
+  Format.fprintf fmt "{ bodies: [%a]; adts: [%a]; allocs: [%a]; source_files: [%a] }"
+    (Format.pp_print_list pp_body) c.bodies
+    (Format.pp_print_list pp_adt_def) c.adts
+    (Format.pp_print_list pp_alloc) c.allocs
+    (Format.pp_print_list pp_source_file) c.source_files
+
+and pp_crate_type (fmt : Format.formatter) (ct : crate_type) =
+  match ct with
+  | Bin -> Format.fprintf fmt "Bin"
+  | Lib -> Format.fprintf fmt "Lib"
+  | Rlib -> Format.fprintf fmt "Rlib"
+  | Dylib -> Format.fprintf fmt "Dylib"
+  | Cdylib -> Format.fprintf fmt "Cdylib"
+  | Staticlib -> Format.fprintf fmt "Staticlib"
+  | ProcMacro -> Format.fprintf fmt "ProcMacro"
+
+and pp_edition (fmt : Format.formatter) (e : edition) =
+  match e with
+  | Edition2015 -> Format.fprintf fmt "Edition2015"
+  | Edition2018 -> Format.fprintf fmt "Edition2018"
+  | Edition2021 -> Format.fprintf fmt "Edition2021"
+  | Edition2024 -> Format.fprintf fmt "Edition2024"
+
+and pp_config (fmt : Format.formatter) (c : config) =
+  let pp_strings = Format.pp_print_list Format.pp_print_string in
+  let pp_extern fmt (name, path) = Format.fprintf fmt "%s=%s" name path in
+  Format.fprintf fmt "{ crate_type: %a; edition: %a; target: %a; crate_name: %a; cfgs: [%a]; externs: [%a]; search_paths: [%a]; unstable_flags: [%a]; codegen_flags: [%a]; phase: %a }"
+    pp_crate_type c.crate_type pp_edition c.edition
+    (Format.pp_print_option Format.pp_print_string) c.target
+    (Format.pp_print_option Format.pp_print_string) c.crate_name
+    pp_strings c.cfgs (Format.pp_print_list pp_extern) c.externs
+    pp_strings c.search_paths pp_strings c.unstable_flags pp_strings c.codegen_flags
+    pp_mir_phase c.phase
+
+and pp_workspace_crate (fmt : Format.formatter) (wc : workspace_crate) =
+  Format.fprintf fmt "{ package: %s; target: %s; crate_mir: %a }"
+    wc.package wc.target pp_crate_mir wc.crate_mir
@@ -1,33 +1,95 @@
 (* Pretty printers for the Rust AST *)
 val pp_mutability : Format.formatter -> Rustc_ast.mutability -> unit
+val pp_safety : Format.formatter -> Rustc_ast.safety -> unit
 val pp_un_op : Format.formatter -> Rustc_ast.un_op -> unit
 val pp_bin_op : Format.formatter -> Rustc_ast.bin_op -> unit
 val pp_null_op : Format.formatter -> Rustc_ast.null_op -> unit
+val pp_pointer_coercion : Format.formatter -> Rustc_ast.pointer_coercion -> unit
+val pp_coercion_source : Format.formatter -> Rustc_ast.coercion_source -> unit
+val pp_cast_kind : Format.formatter -> Rustc_ast.cast_kind -> unit
+val pp_location : Format.formatter -> Rustc_ast.location -> unit
+val pp_expansion_kind : Format.formatter -> Rustc_ast.expansion_kind -> unit
+val pp_expansion : Format.formatter -> Rustc_ast.expansion -> unit
 val pp_span : Format.formatter -> Rustc_ast.span -> unit
 val pp_borrow_kind : Format.formatter -> Rustc_ast.borrow_kind -> unit
+val pp_region : Format.formatter -> Rustc_ast.region -> unit
 val pp_rkind : Format.formatter -> Rustc_ast.rkind -> unit
 val pp_expr_kind : Format.formatter -> Rustc_ast.expr_kind -> unit
 val pp_const_val : Format.formatter -> Rustc_ast.const_val -> unit
+val pp_adt_kind : Format.formatter -> Rustc_ast.adt_kind -> unit
+val pp_fn_sig : Format.formatter -> Rustc_ast.fn_sig -> unit
+val pp_closure_kind : Format.formatter -> Rustc_ast.closure_kind -> unit
+val pp_capture_kind : Format.formatter -> Rustc_ast.capture_kind -> unit
+val pp_upvar : Format.formatter -> Rustc_ast.upvar -> unit
+val pp_coroutine_sig : Format.formatter -> Rustc_ast.coroutine_sig -> unit
+val pp_alias_kind : Format.formatter -> Rustc_ast.alias_kind -> unit
+val pp_trait_ref : Format.formatter -> Rustc_ast.trait_ref -> unit
+val pp_dyn_bound : Format.formatter -> Rustc_ast.dyn_bound -> unit
+val pp_ty_pattern : Format.formatter -> Rustc_ast.ty_pattern -> unit
 val pp_typ : Format.formatter -> Rustc_ast.typ -> unit
+val pp_unevaluated_const : Format.formatter -> Rustc_ast.unevaluated_const -> unit
 val pp_const : Format.formatter -> Rustc_ast.const -> unit
 val pp_arg : Format.formatter -> Rustc_ast.arg -> unit
+val pp_generic_arg : Format.formatter -> Rustc_ast.generic_arg -> unit
 val pp_projection : Format.formatter -> Rustc_ast.projection -> unit
+val pp_projection_kind : Format.formatter -> Rustc_ast.projection_kind -> unit
 val pp_place : Format.formatter -> Rustc_ast.place -> unit
+val pp_var_binding : Format.formatter -> Rustc_ast.var_binding -> unit
+val pp_implicit_self_kind : Format.formatter -> Rustc_ast.implicit_self_kind -> unit
 val pp_local_info : Format.formatter -> Rustc_ast.local_info -> unit
 val pp_local_decl : Format.formatter -> Rustc_ast.local_decl -> unit
 val pp_operand : Format.formatter -> Rustc_ast.operand -> unit
 val pp_intrinsic : Format.formatter -> Rustc_ast.intrinsic -> unit
+val pp_aggregate_kind : Format.formatter -> Rustc_ast.aggregate_kind -> unit
 val pp_rvalue : Format.formatter -> Rustc_ast.rvalue -> unit
+val pp_fake_read_cause : Format.formatter -> Rustc_ast.fake_read_cause -> unit
+val pp_variance : Format.formatter -> Rustc_ast.variance -> unit
+val pp_coverage_kind : Format.formatter -> Rustc_ast.coverage_kind -> unit
+val pp_drop_hint_reason : Format.formatter -> Rustc_ast.drop_hint_reason -> unit
+val pp_user_type_projection : Format.formatter -> Rustc_ast.user_type_projection -> unit
 val pp_statment_kind : Format.formatter -> Rustc_ast.statement_kind -> unit
+val pp_safety_context : Format.formatter -> Rustc_ast.safety_context -> unit
 val pp_statement : Format.formatter -> Rustc_ast.statement -> unit
 val pp_targets : Format.formatter -> Rustc_ast.targets -> unit
+val pp_instance_kind : Format.formatter -> Rustc_ast.instance_kind -> unit
+val pp_instance : Format.formatter -> Rustc_ast.instance -> unit
+val pp_call_target : Format.formatter -> Rustc_ast.call_target -> unit
 val pp_call_info : Format.formatter -> Rustc_ast.call_info -> unit
+val pp_coroutine_kind : Format.formatter -> Rustc_ast.coroutine_kind -> unit
 val pp_assert_message : Format.formatter -> Rustc_ast.assert_message -> unit
 val pp_drop_info : Format.formatter -> Rustc_ast.drop_info -> unit
 val pp_assert_info : Format.formatter -> Rustc_ast.assert_info -> unit
+val pp_yield_info : Format.formatter -> Rustc_ast.yield_info -> unit
+val pp_asm_operand : Format.formatter -> Rustc_ast.asm_operand -> unit
+val pp_inline_asm_info : Format.formatter -> Rustc_ast.inline_asm_info -> unit
 val pp_basic_block : Format.formatter -> Rustc_ast.basic_block -> unit
 val pp_unwind_action : Format.formatter -> Rustc_ast.unwind_action -> unit
 val pp_var_debug_info_content : Format.formatter -> Rustc_ast.var_debug_info_content -> unit
 val pp_var_debug_info : Format.formatter -> Rustc_ast.var_debug_info -> unit
 val pp_var_debug_info_fragment : Format.formatter -> Rustc_ast.var_debug_info_fragment -> unit
-val pp_body : Format.formatter -> Rustc_ast.body -> unit
\ No newline at end of file
+val pp_user_self_ty : Format.formatter -> Rustc_ast.user_self_ty -> unit
+val pp_user_type_kind : Format.formatter -> Rustc_ast.user_type_kind -> unit
+val pp_user_type_annotation : Format.formatter -> Rustc_ast.user_type_annotation -> unit
+val pp_unsafe_op_kind : Format.formatter -> Rustc_ast.unsafe_op_kind -> unit
+val pp_unsafe_op : Format.formatter -> Rustc_ast.unsafe_op -> unit
+val pp_hir_id : Format.formatter -> Rustc_ast.hir_id -> unit
+val pp_source_scope : Format.formatter -> Rustc_ast.source_scope -> unit
+val pp_def_kind : Format.formatter -> Rustc_ast.def_kind -> unit
+val pp_visibility : Format.formatter -> Rustc_ast.visibility -> unit
+val pp_body_header : Format.formatter -> Rustc_ast.body_header -> unit
+val pp_mir_phase : Format.formatter -> Rustc_ast.mir_phase -> unit
+val pp_body : Format.formatter -> Rustc_ast.body -> unit
+val pp_field_def : Format.formatter -> Rustc_ast.field_def -> unit
+val pp_variant_def : Format.formatter -> Rustc_ast.variant_def -> unit
+val pp_repr_options : Format.formatter -> Rustc_ast.repr_options -> unit
+val pp_adt_def : Format.formatter -> Rustc_ast.adt_def -> unit
+val pp_relocation : Format.formatter -> Rustc_ast.relocation -> unit
+val pp_allocation : Format.formatter -> Rustc_ast.allocation -> unit
+val pp_global_alloc : Format.formatter -> Rustc_ast.global_alloc -> unit
+val pp_alloc : Format.formatter -> Rustc_ast.alloc -> unit
+val pp_source_file : Format.formatter -> Rustc_ast.source_file -> unit
+val pp_crate_mir : Format.formatter -> Rustc_ast.crate_mir -> unit
+val pp_crate_type : Format.formatter -> Rustc_ast.crate_type -> unit
+val pp_edition : Format.formatter -> Rustc_ast.edition -> unit
+val pp_config : Format.formatter -> Rustc_ast.config -> unit
+val pp_workspace_crate : Format.formatter -> Rustc_ast.workspace_crate -> unit
@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+
+use rustc_middle::mir::interpret::{AllocId, GlobalAlloc, Scalar};
+use rustc_middle::mir::visit::{TyContext, Visitor};
+use rustc_middle::{mir, ty};
+use rustc_span::def_id::{CRATE_DEF_ID, DefId};
+
+use crate::coherce::{self, Coherce};
+use crate::mir_types;
+
+// Gathers the ADTs mentioned by the visited bodies, in discovery order.
+pub struct AdtCollector<'tcx> {
+    tcx : ty::TyCtxt<'tcx>,
+    seen : HashSet<DefId>,
+    adts : Vec<ty::AdtDef<'tcx>>,
+}
+
+impl<'tcx> AdtCollector<'tcx> {
+    pub fn new(tcx : ty::TyCtxt<'tcx>) -> Self {
+        AdtCollector { tcx, seen : HashSet::new(), adts : Vec::new() }
+    }
+
+    fn add_ty(&mut self, ty : ty::Ty<'tcx>) {
+        for arg in ty.walk() {
+            if let Some(ty) = arg.as_type()
+                && let ty::TyKind::Adt(adt, _) = ty.kind()
+                && self.seen.insert(adt.did())
+            {
+                self.adts.push(*adt);
+            }
+        }
+    }
+
+    pub fn into_table(mut self) -> Vec<mir_types::AdtDef> {
+        // Field types may mention further ADTs, so the list keeps growing
+        // while it is being walked.
+        let mut i = 0;
+        while i < self.adts.len() {
+            let adt = self.adts[i];
+            for field in adt.all_fields() {
+                self.add_ty(self.tcx.type_of(field.did).instantiate_identity());
+            }
+            i += 1;
+        }
+        self.adts
+            .iter()
+            .map(|adt| adt.to_mmir(self.tcx, adt.did()))
+            .collect()
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for AdtCollector<'tcx> {
+    fn visit_ty(&mut self, ty : ty::Ty<'tcx>, _ : TyContext) {
+        self.add_ty(ty);
+    }
+}
+
+// Gathers the allocations referred to by the constants of the visited
+// bodies, as they are lowered, together with everything their provenance
+// points to.
+pub struct AllocCollector<'tcx> {
+    tcx : ty::TyCtxt<'tcx>,
+    // Item of the visited body, whose typing environment evaluates its
+    // constants.
+    owner : DefId,
+    seen : HashSet<AllocId>,
+    allocs : Vec<AllocId>,
+}
+
+impl<'tcx> AllocCollector<'tcx> {
+    pub fn new(tcx : ty::TyCtxt<'tcx>) -> Self {
+        AllocCollector {
+            tcx,
+            owner : CRATE_DEF_ID.to_def_id(),
+            seen : HashSet::new(),
+            allocs : Vec::new(),
+        }
+    }
+
+    fn add(&mut self, id : AllocId) {
+        if self.seen.insert(id) {
+            self.allocs.push(id);
+        }
+    }
+
+    fn add_value(&mut self, value : mir::ConstValue<'tcx>) {
+        match value {
+            mir::ConstValue::Scalar(Scalar::Ptr(ptr, _)) => {
+                let (prov, _) = ptr.into_raw_parts();
+                self.add(prov.alloc_id());
+            },
+            mir::ConstValue::Slice { data, .. } =>
+                self.add(coherce::slice_alloc_id(self.tcx, data)),
+            mir::ConstValue::Indirect { alloc_id, .. } => self.add(alloc_id),
+            _ => (),
+        }
+    }
+
+    // Type-level constants are lowered to the MIR value of their valtree.
+    fn add_ty_const(&mut self, ct : ty::Const<'tcx>) {
+        if let ty::ConstKind::Value(value) = ct.kind() {
+            self.add_value(self.tcx.valtree_to_const_val(value));
+        }
+    }
+
+    pub fn into_table(mut self) -> Vec<mir_types::Alloc> {
+        let mut i = 0;
+        while i < self.allocs.len() {
+            if let GlobalAlloc::Memory(alloc) = self.tcx.global_alloc(self.allocs[i]) {
+                for (_, prov) in alloc.inner().provenance().ptrs().iter() {
+                    self.add(prov.alloc_id());
+                }
+            }
+            i += 1;
+        }
+        self.allocs
+            .iter()
+            .map(|id| mir_types::Alloc {
+                id : id.0.get() as u32,
+                kind : self.tcx.global_alloc(*id).to_mmir(self.tcx, CRATE_DEF_ID.to_def_id()),
+            })
+            .collect()
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for AllocCollector<'tcx> {
+    fn visit_body(&mut self, body : &mir::Body<'tcx>) {
+        self.owner = body.source.def_id();
+        self.super_body(body);
+    }
+
+    fn visit_const_operand(&mut self, constant : &mir::ConstOperand<'tcx>, location : mir::Location) {
+        match constant.const_ {
+            mir::Const::Val(value, _) => self.add_value(value),
+            // The value lowered along with an unevaluated constant.
+            mir::Const::Unevaluated(..) => {
+                if let Some(value) = coherce::eval_unevaluated(self.tcx, self.owner, constant) {
+                    self.add_value(value);
+                }
+            },
+            mir::Const::Ty(..) => (),
+        }
+        self.super_const_operand(constant, location);
+    }
+
+    fn visit_ty_const(&mut self, ct : ty::Const<'tcx>, _ : mir::Location) {
+        self.add_ty_const(ct);
+    }
+
+    fn visit_ty(&mut self, ty : ty::Ty<'tcx>, _ : TyContext) {
+        for arg in ty.walk() {
+            if let Some(ct) = arg.as_const() {
+                self.add_ty_const(ct);
+            }
+        }
+    }
+}
+
+// Every file of the source map, in the order used by `Location.file`. To be
+// called once all the spans have been lowered, since lowering a span coming
+// from another crate imports its file.
+pub fn source_files(tcx : ty::TyCtxt<'_>) -> Vec<mir_types::SourceFile> {
+    tcx.sess
+        .source_map()
+        .files()
+        .iter()
+        .map(|file| mir_types::SourceFile {
+            name : file.name.prefer_local().to_string(),
+            start_pos : file.start_pos.0,
+            end_pos : file.end_position().0,
+            src : file.src.as_ref().map(|src| src.to_string()),
+        })
+        .collect()
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use rustc_hir::def::DefKind;
+use rustc_hir::{self as hir, intravisit};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::{mir, ty};
+use rustc_span::Span;
+use rustc_span::def_id::DefId;
+
+use crate::coherce::{self, Coherce};
+use crate::mir_types::{self, SafetyContext, UnsafeOpKind};
+
+// Unsafe blocks of a HIR body, closures included.
+struct UnsafeBlocks<'tcx> {
+    tcx : ty::TyCtxt<'tcx>,
+    blocks : Vec<(Span, SafetyContext)>,
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for UnsafeBlocks<'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn maybe_tcx(&mut self) -> Self::MaybeTyCtxt {
+        self.tcx
+    }
+
+    fn visit_block(&mut self, block : &'tcx hir::Block<'tcx>) {
+        if let hir::BlockCheckMode::UnsafeBlock(source) = block.rules {
+            let context = match source {
+                hir::UnsafeSource::UserProvided => SafetyContext::UnsafeBlock,
+                hir::UnsafeSource::CompilerGenerated => SafetyContext::BuiltinUnsafe,
+            };
+            self.blocks.push((block.span, context));
+        }
+        intravisit::walk_block(self, block);
+    }
+}
+
+// Safety regions of the source of one item. Only the HIR of the local crate
+// is available, so the unsafe blocks of other crates are not known.
+struct Regions {
+    blocks : Vec<(Span, SafetyContext)>,
+    unsafe_fn : bool,
+    local : bool,
+}
+
+impl Regions {
+    fn new(tcx : ty::TyCtxt<'_>, did : DefId) -> Self {
+        // Closures are written inside the body of their parent.
+        let root = tcx.typeck_root_def_id(did);
+        let mut finder = UnsafeBlocks { tcx, blocks : Vec::new() };
+        if let Some(local) = root.as_local()
+            && let Some(body) = tcx.hir_maybe_body_owned_by(local)
+        {
+            intravisit::Visitor::visit_body(&mut finder, body);
+        }
+        let unsafe_fn = matches!(tcx.def_kind(root), DefKind::Fn | DefKind::AssocFn)
+            && tcx.fn_sig(root).skip_binder().safety().is_unsafe();
+        Regions { blocks : finder.blocks, unsafe_fn, local : root.is_local() }
+    }
+
+    fn context(&self, span : Span) -> SafetyContext {
+        // Code expanded from a macro is unsafe if the macro is called from
+        // an unsafe block.
+        let spans = [span, span.source_callsite()];
+        let block = self
+            .blocks
+            .iter()
+            .filter(|(block, _)| spans.iter().any(|s| block.contains(*s)))
+            .min_by_key(|(block, _)| block.hi().0 - block.lo().0)
+            .map(|(_, context)| *context);
+        match block {
+            Some(context) => context,
+            None if self.unsafe_fn => SafetyContext::UnsafeFn,
+            // Code inlined from another crate may be in an unsafe block.
+            None if !self.local => SafetyContext::Unknown,
+            None => SafetyContext::Safe,
+        }
+    }
+}
+
+// Locals holding the address of a mutable or extern static, with the
+// operation their dereference is. MIR reads and writes a static through a
+// pointer to it, loaded from a constant.
+fn static_ptrs<'tcx>(
+    tcx : ty::TyCtxt<'tcx>,
+    body : &mir::Body<'tcx>,
+) -> HashMap<mir::Local, UnsafeOpKind> {
+    let mut statics = HashMap::new();
+    for stmt in body.basic_blocks.iter().flat_map(|data| &data.statements) {
+        if let mir::StatementKind::Assign(assign) = &stmt.kind
+            && let (place, mir::Rvalue::Use(mir::Operand::Constant(constant))) = &**assign
+            && let Some(local) = place.as_local()
+            && let Some(did) = constant.check_static_ptr(tcx)
+        {
+            let path = coherce::def_path(tcx, did);
+            if tcx.is_mutable_static(did) {
+                statics.insert(local, UnsafeOpKind::UseOfMutableStatic(path));
+            } else if tcx.is_foreign_item(did) {
+                statics.insert(local, UnsafeOpKind::UseOfExternStatic(path));
+            }
+        }
+    }
+    statics
+}
+
+// Unsafe operations of a MIR body. After drop elaboration, dereferences of
+// a `Box` are done through raw pointers and are reported as well.
+struct UnsafeOps<'a, 'tcx> {
+    tcx : ty::TyCtxt<'tcx>,
+    body : &'a mir::Body<'tcx>,
+    statics : HashMap<mir::Local, UnsafeOpKind>,
+    ops : Vec<(UnsafeOpKind, mir::Location)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeOps<'a, 'tcx> {
+    fn visit_place(
+        &mut self,
+        place : &mir::Place<'tcx>,
+        context : PlaceContext,
+        location : mir::Location,
+    ) {
+        let raw_borrow = matches!(
+            context,
+            PlaceContext::NonMutatingUse(NonMutatingUseContext::RawBorrow)
+                | PlaceContext::MutatingUse(MutatingUseContext::RawBorrow)
+        );
+        // Taking the address of a union field is safe, writing to it too.
+        let reads = !context.is_mutating_use() && !raw_borrow;
+        let static_op = match place.projection.first() {
+            Some(mir::ProjectionElem::Deref) => self.statics.get(&place.local),
+            _ => None,
+        };
+        // Taking the address of a static is safe too, unless a pointer is
+        // read on the way.
+        let address_only = raw_borrow
+            && !place.projection.iter().skip(1).any(|elem| matches!(elem, mir::ProjectionElem::Deref));
+        if let Some(op) = static_op && !address_only {
+            self.ops.push((op.clone(), location));
+        }
+        for (i, (base, elem)) in place.iter_projections().enumerate() {
+            let base_ty = base.ty(self.body, self.tcx).ty;
+            match elem {
+                // The dereference of a static pointer is the use of the static.
+                mir::ProjectionElem::Deref if base_ty.is_raw_ptr() && (i > 0 || static_op.is_none()) => {
+                    self.ops.push((UnsafeOpKind::DerefOfRawPointer, location));
+                }
+                mir::ProjectionElem::Field(..) if reads && base_ty.is_union() => {
+                    self.ops.push((UnsafeOpKind::AccessToUnionField, location));
+                }
+                _ => (),
+            }
+        }
+        self.super_place(place, context, location);
+    }
+
+    fn visit_terminator(&mut self, terminator : &mir::Terminator<'tcx>, location : mir::Location) {
+        match &terminator.kind {
+            mir::TerminatorKind::Call { func, .. } | mir::TerminatorKind::TailCall { func, .. } => {
+                let func_ty = func.ty(self.body, self.tcx);
+                if func_ty.is_fn() && func_ty.fn_sig(self.tcx).safety().is_unsafe() {
+                    let callee = func.const_fn_def().map(|(did, _)| coherce::def_path(self.tcx, did));
+                    self.ops.push((UnsafeOpKind::CallToUnsafeFunction(callee), location));
+                }
+            }
+            mir::TerminatorKind::InlineAsm { .. } => {
+                self.ops.push((UnsafeOpKind::UseOfInlineAssembly, location));
+            }
+            _ => (),
+        }
+        self.super_terminator(terminator, location);
+    }
+}
+
+// Safety of the statements of a MIR body, by looking up the unsafe blocks
+// of the HIR they come from.
+pub struct UnsafetyMap<'a, 'tcx> {
+    tcx : ty::TyCtxt<'tcx>,
+    def_id : DefId,
+    body : &'a mir::Body<'tcx>,
+    regions : HashMap<DefId, Regions>,
+}
+
+impl<'a, 'tcx> UnsafetyMap<'a, 'tcx> {
+    pub fn new(tcx : ty::TyCtxt<'tcx>, body : &'a mir::Body<'tcx>, def_id : DefId) -> Self {
+        UnsafetyMap { tcx, def_id, body, regions : HashMap::new() }
+    }
+
+    // Statements inlined from another function are looked up in the source
+    // of that function.
+    fn context(&mut self, source_info : &mir::SourceInfo) -> SafetyContext {
+        let tcx = self.tcx;
+        let owner = source_info
+            .scope
+            .inlined_instance(&self.body.source_scopes)
+            .map_or(self.def_id, |instance| instance.def_id());
+        self.regions
+            .entry(owner)
+            .or_insert_with(|| Regions::new(tcx, owner))
+            .context(source_info.span)
+    }
+
+    // Sets the safety of the statements lowered from the body, terminators
+    // being the last statement of their block.
+    pub fn annotate(&mut self, blocks : &mut [mir_types::BasicBlock]) {
+        let body = self.body;
+        for (data, block) in body.basic_blocks.iter().zip(blocks) {
+            let infos = data
+                .statements
+                .iter()
+                .map(|s| &s.source_info)
+                .chain(data.terminator.iter().map(|t| &t.source_info));
+            for (info, stmt) in infos.zip(&mut block.statements) {
+                stmt.safety = self.context(info);
+            }
+        }
+    }
+
+    pub fn unsafe_ops(&mut self) -> Vec<mir_types::UnsafeOp> {
+        let body = self.body;
+        let statics = static_ptrs(self.tcx, body);
+        let mut collector = UnsafeOps { tcx : self.tcx, body, statics, ops : Vec::new() };
+        collector.visit_body(body);
+        collector
+            .ops
+            .into_iter()
+            .map(|(kind, location)| {
+                let info = body.source_info(location);
+                mir_types::UnsafeOp {
+                    kind,
+                    block : location.block.as_u32(),
+                    statement : location.statement_index as u32,
+                    span : info.span.to_mmir(self.tcx, self.def_id),
+                    safety : self.context(info),
+                }
+            })
+            .collect()
+    }
+}
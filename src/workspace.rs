@@ -0,0 +1,323 @@
+// Extraction of the crates of a Cargo workspace, without network access.
+//
+// The crate graph comes from `cargo metadata --offline` (or from its saved
+// output). Every library of the graph is compiled to metadata, in dependency
+// order, by the `rustc` of `$RUSTC`, which must be the toolchain this crate
+// is built against: the driver used for the extraction does not write any
+// artifact. The MIR of the workspace members is then extracted in-process.
+// Build scripts are not run, so crates relying on generated code fail.
+//
+// Dependencies are only compiled with `--emit=metadata`, which carries no
+// optimized MIR: unlike in a real build, their functions cannot be inlined
+// into the `Optimized` MIR of the members.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::mir_types::{self, CrateType, Edition};
+
+#[derive(Deserialize)]
+struct Metadata {
+    packages : Vec<Package>,
+    workspace_members : Vec<String>,
+    resolve : Option<Resolve>,
+    target_directory : PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    id : String,
+    name : String,
+    targets : Vec<Target>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name : String,
+    kind : Vec<String>,
+    src_path : PathBuf,
+    edition : String,
+}
+
+#[derive(Deserialize)]
+struct Resolve {
+    nodes : Vec<Node>,
+}
+
+#[derive(Deserialize)]
+struct Node {
+    id : String,
+    deps : Vec<NodeDep>,
+    features : Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NodeDep {
+    name : String,
+    pkg : String,
+    dep_kinds : Vec<DepKind>,
+}
+
+#[derive(Deserialize)]
+struct DepKind {
+    kind : Option<String>,
+}
+
+impl NodeDep {
+    // Dev and build dependencies are not needed to compile the crate itself.
+    fn is_normal(&self) -> bool {
+        self.dep_kinds.iter().any(|k| k.kind.is_none())
+    }
+}
+
+impl Target {
+    fn is_lib(&self) -> bool {
+        self.kind
+            .iter()
+            .any(|k| matches!(k.as_str(), "lib" | "rlib" | "dylib" | "cdylib" | "staticlib" | "proc-macro"))
+    }
+
+    fn is_proc_macro(&self) -> bool {
+        self.kind.iter().any(|k| k == "proc-macro")
+    }
+
+    fn is_bin(&self) -> bool {
+        self.kind.iter().any(|k| k == "bin")
+    }
+
+    fn crate_name(&self) -> String {
+        self.name.replace('-', "_")
+    }
+
+    fn edition(&self) -> Edition {
+        match self.edition.as_str() {
+            "2015" => Edition::Edition2015,
+            "2018" => Edition::Edition2018,
+            "2021" => Edition::Edition2021,
+            _ => Edition::Edition2024,
+        }
+    }
+}
+
+fn rustc() -> String {
+    std::env::var("RUSTC").unwrap_or("rustc".to_string())
+}
+
+fn host_triple() -> Option<String> {
+    let output = Command::new(rustc()).arg("-vV").output().ok()?;
+    String::from_utf8(output.stdout)
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("host: ").map(String::from))
+}
+
+// Reads the metadata of the workspace of `manifest`, or `manifest` itself if
+// it is the saved output of `cargo metadata --format-version 1`.
+fn metadata(manifest : &Path, platform : Option<&str>) -> Result<Metadata, String> {
+    let json = if manifest.extension().is_some_and(|ext| ext == "json") {
+        std::fs::read_to_string(manifest).map_err(|e| format!("{}: {}", manifest.display(), e))?
+    } else {
+        let mut cargo = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()));
+        cargo
+            .args(["metadata", "--offline", "--format-version", "1", "--manifest-path"])
+            .arg(manifest);
+        if let Some(platform) = platform {
+            cargo.args(["--filter-platform", platform]);
+        }
+        let output = cargo.output().map_err(|e| format!("cannot run cargo: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+        }
+        String::from_utf8(output.stdout).map_err(|e| e.to_string())?
+    };
+    serde_json::from_str(&json).map_err(|e| format!("invalid cargo metadata: {}", e))
+}
+
+// Packages reachable from the workspace members, each after its
+// dependencies.
+fn build_order<'a>(resolve : &'a Resolve, members : &[String]) -> Vec<&'a Node> {
+    fn visit<'a>(
+        id : &str,
+        nodes : &HashMap<&str, &'a Node>,
+        seen : &mut HashSet<&'a str>,
+        order : &mut Vec<&'a Node>,
+    ) {
+        let Some(node) = nodes.get(id).copied() else { return };
+        if !seen.insert(node.id.as_str()) {
+            return;
+        }
+        for dep in node.deps.iter().filter(|dep| dep.is_normal()) {
+            visit(&dep.pkg, nodes, seen, order);
+        }
+        order.push(node);
+    }
+
+    let nodes : HashMap<&str, &Node> = resolve.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    for member in members {
+        visit(member, &nodes, &mut seen, &mut order);
+    }
+    order
+}
+
+// Suffix telling apart the artifacts of packages with the same name. It is
+// the FNV-1a hash of the package id, so that it does not change with the
+// toolchain.
+fn disambiguator(id : &str) -> String {
+    let hash = id
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    format!("{:016x}", hash)
+}
+
+// Compiles a library to the artifact its dependents are given with
+// `--extern`: its metadata, or the dynamic library of a procedural macro.
+fn compile_library(
+    target : &Target,
+    id : &str,
+    options : &mir_types::Config,
+    out_dir : &Path,
+) -> Result<PathBuf, String> {
+    let crate_name = target.crate_name();
+    let suffix = disambiguator(id);
+    let mut args = crate::rustc_args(target.src_path.display().to_string(), options);
+    // `rustc_args` names the program and asks for MIR, replace both.
+    args.remove(0);
+    args.retain(|arg| arg != "--emit=mir");
+    args.extend([
+        "--cap-lints".to_string(),
+        "allow".to_string(),
+        "-C".to_string(),
+        format!("metadata={}", suffix),
+        "-C".to_string(),
+        format!("extra-filename=-{}", suffix),
+        "--out-dir".to_string(),
+        out_dir.display().to_string(),
+    ]);
+    let artifact = if target.is_proc_macro() {
+        args.extend(["--emit=link".to_string(), "--extern".to_string(), "proc_macro".to_string()]);
+        let file = format!(
+            "{}{}-{}{}",
+            std::env::consts::DLL_PREFIX,
+            crate_name,
+            suffix,
+            std::env::consts::DLL_SUFFIX,
+        );
+        out_dir.join(file)
+    } else {
+        args.push("--emit=metadata".to_string());
+        out_dir.join(format!("lib{}-{}.rmeta", crate_name, suffix))
+    };
+    let output = Command::new(rustc())
+        .args(&args)
+        .output()
+        .map_err(|e| format!("cannot run rustc: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "compilation of {} failed:\n{}",
+            crate_name,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(artifact)
+}
+
+// Extracts the MIR of the library and binaries of every workspace member.
+// `config` gives the phase, target, extra cfgs, externs and flags; the rest
+// of the options comes from the manifests.
+pub fn extract(
+    manifest : &Path,
+    config : &mir_types::Config,
+) -> Result<Vec<mir_types::WorkspaceCrate>, String> {
+    let platform = config.target.clone().or_else(host_triple);
+    let metadata = metadata(manifest, platform.as_deref())?;
+    let resolve = metadata
+        .resolve
+        .as_ref()
+        .ok_or("cargo metadata has no dependency resolution")?;
+    let packages : HashMap<&str, &Package> =
+        metadata.packages.iter().map(|p| (p.id.as_str(), p)).collect();
+    let members : HashSet<&str> = metadata.workspace_members.iter().map(|m| m.as_str()).collect();
+    let out_dir = metadata.target_directory.join("mir-parser").join("deps");
+    std::fs::create_dir_all(&out_dir).map_err(|e| format!("{}: {}", out_dir.display(), e))?;
+
+    // Extern name and artifact of the library of each compiled package.
+    let mut artifacts : HashMap<&str, (String, PathBuf)> = HashMap::new();
+    let mut crates = Vec::new();
+    for node in build_order(resolve, &metadata.workspace_members) {
+        let package = packages
+            .get(node.id.as_str())
+            .ok_or_else(|| format!("unknown package {}", node.id))?;
+        let cfgs : Vec<String> = node
+            .features
+            .iter()
+            .map(|f| format!("feature=\"{}\"", f))
+            .chain(config.cfgs.iter().cloned())
+            .collect();
+        let externs : Vec<(String, String)> = node
+            .deps
+            .iter()
+            .filter(|dep| dep.is_normal())
+            .filter_map(|dep| {
+                let (_, path) = artifacts.get(dep.pkg.as_str())?;
+                Some((dep.name.clone(), path.display().to_string()))
+            })
+            .collect();
+        let mut search_paths = config.search_paths.clone();
+        search_paths.push(format!("dependency={}", out_dir.display()));
+        let options = |target : &Target, crate_type, mut externs : Vec<(String, String)>| mir_types::Config {
+            crate_type,
+            edition : target.edition(),
+            // Procedural macros run in the compiler, on the host.
+            target : if target.is_proc_macro() { None } else { config.target.clone() },
+            crate_name : Some(target.crate_name()),
+            cfgs : cfgs.clone(),
+            externs : {
+                externs.extend(config.externs.iter().cloned());
+                externs
+            },
+            search_paths : search_paths.clone(),
+            unstable_flags : config.unstable_flags.clone(),
+            codegen_flags : config.codegen_flags.clone(),
+            phase : config.phase,
+        };
+
+        let lib = package.targets.iter().find(|t| t.is_lib());
+        if let Some(lib) = lib {
+            let crate_type = if lib.is_proc_macro() { CrateType::ProcMacro } else { CrateType::Lib };
+            let artifact = compile_library(lib, &node.id, &options(lib, crate_type, externs.clone()), &out_dir)?;
+            artifacts.insert(node.id.as_str(), (lib.crate_name(), artifact));
+        }
+        if !members.contains(node.id.as_str()) {
+            continue;
+        }
+        for target in package.targets.iter().filter(|t| t.is_lib() || t.is_bin()) {
+            let (crate_type, externs) = if target.is_bin() {
+                // Binaries use the library of their package.
+                let mut externs = externs.clone();
+                if let Some((name, path)) = artifacts.get(node.id.as_str()) {
+                    externs.push((name.clone(), path.display().to_string()));
+                }
+                (CrateType::Bin, externs)
+            } else if target.is_proc_macro() {
+                (CrateType::ProcMacro, externs.clone())
+            } else {
+                (CrateType::Lib, externs.clone())
+            };
+            let input = target.src_path.display().to_string();
+            let args = crate::rustc_args(input, &options(target, crate_type, externs));
+            let crate_mir = crate::extract_with_args(&args, config.phase, false)
+                .ok_or_else(|| format!("compilation of {} ({}) failed", package.name, target.name))?;
+            crates.push(mir_types::WorkspaceCrate {
+                package : package.name.clone(),
+                target : target.name.clone(),
+                crate_mir,
+            });
+        }
+    }
+    Ok(crates)
+}
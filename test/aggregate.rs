@@ -0,0 +1,23 @@
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+enum Shape {
+    Circle(Point, u32),
+    Square { corner: Point, side: u32 },
+}
+
+fn main() -> () {
+    let origin = Point { x: 0, y: 0 };
+    let pair = (origin.x, origin.y);
+    let shape = Shape::Circle(Point { x: pair.0, y: pair.1 }, 3);
+    let side = 2;
+    let scale = |s: u32| s * side;
+    let area = match shape {
+        Shape::Circle(_, r) => scale(r * r * 3),
+        Shape::Square { side, .. } => scale(side * side),
+    };
+    let _other = Shape::Square { corner: Point { x: 1, y: 1 }, side: 1 };
+    let _list = [area, area + 1];
+}
@@ -0,0 +1,47 @@
+#![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+
+use std::ops::Coroutine;
+use std::pin::Pin;
+
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum Level {
+    Low = 1,
+    High = 2,
+}
+
+// Checked by an `InvalidEnumConstruction` assertion when debug assertions
+// are enabled.
+fn level(raw: u8) -> Level {
+    unsafe { std::mem::transmute::<u8, Level>(raw) }
+}
+
+fn twice(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    // Resuming the coroutine once it returned or panicked is asserted against.
+    let mut coroutine = #[coroutine] || {
+        yield 1u32;
+    };
+    let _ = Pin::new(&mut coroutine).resume(());
+
+    let _ = matches!(level(2), Level::High | Level::Low);
+
+    let small = 300u32 as u8;
+    let float = small as f64;
+    let back = float as i32;
+    let single = float as f32;
+    let mut value = 5u32;
+    let ptr: *mut u32 = &mut value;
+    let cptr = ptr as *const u32;
+    let bytes = cptr as *const u8;
+    let addr = bytes as usize;
+    let again = addr as *const u8;
+    let f: fn(u32) -> u32 = twice;
+    let fptr = f as *const ();
+    let c: fn(u32) -> u32 = |x| x + 1;
+    let slice: &[u32] = &[1, 2, 3];
+    let _ = (back, single, again, fptr, c(1), slice);
+}
@@ -0,0 +1,30 @@
+#![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+
+use std::ops::Coroutine;
+use std::pin::Pin;
+
+async fn add(x: u32, y: u32) -> u32 {
+    x + y
+}
+
+fn main() {
+    let mut count = 0;
+    let name = String::from("closure");
+
+    let by_ref = || count + 1;
+    let _ = by_ref();
+    let mut by_mut = || count += 1;
+    by_mut();
+    let by_value = move || name.len();
+    let _ = by_value();
+
+    let async_closure = async |x: u32| add(x, count).await;
+    let _future = async_closure(1);
+
+    let mut coroutine = #[coroutine] |resume: u32| {
+        let doubled: u32 = yield resume * 2;
+        doubled
+    };
+    let _ = Pin::new(&mut coroutine).resume(1);
+    let _ = Pin::new(&mut coroutine).resume(2);
+}
@@ -0,0 +1,18 @@
+// Only compiles as configured by the test: a 2021 edition library named
+// `configured`, with the `extra` feature and without overflow checks.
+
+pub fn base() -> u32 {
+    // `gen` is a keyword from the 2024 edition on.
+    let gen = 1;
+    gen
+}
+
+#[cfg(feature = "extra")]
+pub fn extra() -> u32 {
+    base() + 1
+}
+
+#[cfg(not(feature = "extra"))]
+pub fn extra() -> u32 {
+    compile_error!("the `extra` feature is not set")
+}
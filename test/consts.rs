@@ -0,0 +1,22 @@
+const LIMIT: u64 = 1 << 40;
+
+const GREETING: &str = "hello";
+
+trait Sized2 {
+    const SIZE: usize;
+}
+
+impl Sized2 for u32 {
+    const SIZE: usize = 4;
+}
+
+fn size_of<T: Sized2>() -> usize {
+    T::SIZE
+}
+
+fn main() {
+    let x = LIMIT + 1;
+    let y = size_of::<u32>() + <u32 as Sized2>::SIZE;
+    let z = GREETING.len();
+    let _ = (x, y, z);
+}
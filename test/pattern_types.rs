@@ -0,0 +1,15 @@
+#![feature(pattern_types, pattern_type_macro)]
+#![allow(incomplete_features)]
+
+use std::pat::pattern_type;
+
+type Percent = pattern_type!(u8 is 0..=100);
+
+fn raw(p: Percent) -> u8 {
+    unsafe { std::mem::transmute::<Percent, u8>(p) }
+}
+
+fn main() {
+    let p = unsafe { std::mem::transmute::<u8, Percent>(42) };
+    let _ = raw(p);
+}
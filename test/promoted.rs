@@ -0,0 +1,8 @@
+fn answer() -> &'static i32 {
+    &42
+}
+
+fn main() {
+    let xs: &'static [u8] = &[1, 2, 3];
+    let _ = (answer(), xs);
+}
@@ -0,0 +1,40 @@
+#![feature(coroutines, coroutine_trait, stmt_expr_attributes, explicit_tail_calls)]
+#![allow(incomplete_features)]
+
+use std::arch::asm;
+use std::ops::Coroutine;
+use std::pin::Pin;
+
+fn countdown(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    become countdown(n - 1)
+}
+
+fn main() {
+    let mut x: u64 = 1;
+    unsafe {
+        asm!("shl {0}, 1", inout(reg) x);
+    }
+
+    let mut i = 0;
+    loop {
+        i += 1;
+        if i > 3 {
+            break;
+        }
+    }
+
+    let parity = match i {
+        n if n % 2 == 0 => "even",
+        _ => "odd",
+    };
+
+    let mut coroutine = #[coroutine] || {
+        yield 1u32;
+        2u32
+    };
+    let _ = Pin::new(&mut coroutine).resume(());
+    let _ = (x, parity, countdown(3));
+}
@@ -1,17 +1,282 @@
-let () =
-  let open Sys in
-  let dir = "." in
-  let files = readdir dir |> Array.to_list in
-  let rs_files = List.filter (fun f -> Filename.check_suffix f ".rs") files in
+(* Extracts the MIR of a sample and checks the constructs the sample is
+   written for. An optional mode selects the phase or the configuration of
+   the extraction, or, for `workspace`, extracts a Cargo workspace. Checks
+   only look at names and shapes, not at spans, ids or paths of the standard
+   library, which change from one toolchain to the other. *)
+open Rustc_parser.Rustc_ast
+
+let extract file mode =
+  let result =
+    match mode with
+    | None -> get_mir file
+    | Some "built" -> get_mir_at_phase file Built
+    | Some "promoted" -> get_mir_at_phase file Promoted
+    | Some "drops-elaborated" -> get_mir_at_phase file DropsElaborated
+    | Some "optimized" -> get_mir_at_phase file Optimized
+    | Some "config" ->
+        let config = { (default_config ()) with
+                       crate_type = Lib; edition = Edition2021;
+                       crate_name = Some "configured";
+                       cfgs = ["feature=\"extra\""];
+                       codegen_flags = ["overflow-checks=off"] } in
+        get_mir_with_config file config
+    | Some mode -> failwith ("unknown mode " ^ mode)
+  in
+  match result with Ok mir -> mir | Error msg -> failwith msg
+
+let check what cond = if not cond then failwith ("check failed: " ^ what)
+
+let body (mir : crate_mir) name =
+  match List.find_opt (fun (b : body) -> b.header.name = Some name) mir.bodies with
+  | Some b -> b
+  | None -> failwith ("no body named " ^ name)
+
+let statements (b : body) =
+  List.concat_map
+    (fun (bb : basic_block) -> List.map (fun (s : statement) -> s.skind) bb.statements)
+    b.stmts
+
+let all_statements (mir : crate_mir) = List.concat_map statements mir.bodies
+
+let rvalues b =
+  List.filter_map (function Assign (_, rv) -> Some rv | _ -> None) (statements b)
+
+let operands (s : statement_kind) : operand list =
+  match s with
+  | Assign (_, (Use o | UnaryOp (_, o) | Cast (_, o, _) | Repeat (o, _))) -> [o]
+  | Assign (_, BinaryOp (_, a, b)) -> [a; b]
+  | Assign (_, Aggregate (_, os)) -> os
+  | Call c -> c.func :: c.args
+  | TailCall (f, args) -> f :: args
+  | SwitchInt (o, _) -> [o]
+  | Assert a -> [a.cond]
+  | _ -> []
+
+let unevaluated b =
+  List.filter_map
+    (function (Constant (Unevaluated uv) : operand) -> Some uv | _ -> None)
+    (List.concat_map operands (statements b))
+
+let local_types (mir : crate_mir) =
+  List.concat_map
+    (fun (b : body) -> List.map (fun (d : local_decl) -> d.typ) b.local_decls)
+    mir.bodies
+
+let unsafe_ops (b : body) = List.map (fun (op : unsafe_op) -> op.kind) b.unsafe_ops
+
+let ends_with suffix s = String.ends_with ~suffix s
+
+let check_aggregate mir =
+  let main = body mir "main" in
+  let adt name variant =
+    List.exists
+      (function
+        | (Aggregate (Adt (path, v, _, _), _) : rvalue) -> ends_with name path && v = variant
+        | _ -> false)
+      (rvalues main)
+  in
+  check "struct literal" (adt "Point" 0l);
+  check "tuple variant" (adt "Shape" 0l);
+  check "struct variant" (adt "Shape" 1l);
+  check "tuple" (List.exists (function (Aggregate (Tuple, _) : rvalue) -> true | _ -> false) (rvalues main));
+  check "array" (List.exists (function (Aggregate (Array _, _) : rvalue) -> true | _ -> false) (rvalues main));
+  check "closure"
+    (List.exists (function (Aggregate (Closure _, _) : rvalue) -> true | _ -> false) (rvalues main));
+  check "array length"
+    (List.exists
+       (function (Array (_, Val (ScalarInt ("0x2", _), USize)) : typ) -> true | _ -> false)
+       (local_types mir));
+  check "enum definition"
+    (List.exists
+       (fun (adt : adt_def) ->
+         adt.path = "aggregate::Shape" && adt.kind = Enum && List.length adt.variants = 2)
+       mir.adts)
+
+let check_borrow mir =
+  check "mutable borrow"
+    (List.exists
+       (function (Ref (_, Mut _, _) : rvalue) -> true | _ -> false)
+       (rvalues (body mir "main")))
+
+(* Constants and statics are extracted from the MIR of the const evaluator,
+   which is never optimized. *)
+let check_consts ~phase mir =
+  check "phase" ((body mir "main").phase = phase);
+  check "constant body" ((body mir "LIMIT").phase = DropsElaborated);
+  let main = unevaluated (body mir "main") in
+  check "evaluated constant"
+    (List.exists
+       (fun (uv : unevaluated_const) ->
+         ends_with "LIMIT" uv.def_path && uv.value = Some (ScalarInt ("0x10000000000", 8)))
+       main);
+  check "evaluated associated constant"
+    (List.exists
+       (fun (uv : unevaluated_const) ->
+         ends_with "SIZE" uv.def_path && uv.value = Some (ScalarInt ("0x4", 8)))
+       main);
+  check "allocation of an evaluated constant"
+    (List.exists
+       (fun (uv : unevaluated_const) ->
+         match uv.value with
+         | Some (Slice (id, _, _) | Indirect (id, _) | ScalarPtr (id, _, _)) ->
+             ends_with "GREETING" uv.def_path
+             && List.exists (fun (alloc : alloc) -> alloc.id = id) mir.allocs
+         | _ -> false)
+       main);
+  check "generic associated constant"
+    (List.exists
+       (fun (uv : unevaluated_const) -> ends_with "SIZE" uv.def_path && uv.value = None)
+       (unevaluated (body mir "size_of")))
+
+let check_loop mir =
+  check "iterator call"
+    (List.exists
+       (function
+         | Call { resolved = Resolved instance; _ } -> ends_with "next" instance.def_path
+         | _ -> false)
+       (statements (body mir "main")))
+
+(* Past promotion, promoted constants are only kept as runtime MIR. *)
+let check_promoted ~phase mir =
+  let answer = body mir "answer" in
+  check "promoted body"
+    (match answer.promoted with
+     | [ (p : body) ] -> p.header.promoted = Some 0l && p.phase = phase
+     | _ -> false);
+  check "promoted reference"
+    (List.exists (fun (uv : unevaluated_const) -> uv.promoted = Some 0l) (unevaluated answer))
+
+let check_unsafe mir =
+  let main = body mir "main" in
+  check "raw dereference"
+    (List.exists
+       (fun (op : unsafe_op) -> op.kind = DerefOfRawPointer && op.safety = UnsafeBlock)
+       main.unsafe_ops);
+  check "safe raw borrow"
+    (List.exists
+       (fun (bb : basic_block) ->
+         List.exists
+           (fun (s : statement) ->
+             match s.skind with Assign (_, RawPtr _) -> s.safety = (Safe : safety_context) | _ -> false)
+           bb.statements)
+       main.stmts)
+
+let check_unsafe_ops mir =
+  let ops = unsafe_ops (body mir "main") in
+  let exists p = List.exists p ops in
+  check "union field" (List.mem AccessToUnionField ops);
+  check "mutable static" (exists (function UseOfMutableStatic path -> ends_with "COUNTER" path | _ -> false));
+  check "extern static" (exists (function UseOfExternStatic path -> ends_with "environ" path | _ -> false));
+  check "inline assembly" (List.mem UseOfInlineAssembly ops);
+  check "address of a mutable static" ((body mir "counter_address").unsafe_ops = [])
+
+let check_closures mir =
+  let types = local_types mir in
+  let captures =
+    List.concat_map
+      (function
+        | (Closure (_, _, upvars) : typ) -> List.map (fun (u : upvar) -> u.capture) upvars
+        | _ -> [])
+      types
+  in
+  check "capture by reference" (List.mem ByRef captures);
+  check "capture by mutable reference" (List.mem ByMutRef captures);
+  check "capture by value" (List.mem ByValue captures);
+  check "async closure"
+    (List.exists (function (CoroutineClosure _ : typ) -> true | _ -> false) types);
+  check "coroutine" (List.exists (function (Coroutine _ : typ) -> true | _ -> false) types)
+
+(* Yields and false edges only exist until the coroutine transform and the
+   removal of false edges. *)
+let check_terminators ~built mir =
+  let stmts = all_statements mir in
+  let exists p = List.exists p stmts in
+  check "inline assembly" (exists (function InlineAsm _ -> true | _ -> false));
+  check "tail call" (exists (function TailCall _ -> true | _ -> false));
+  check "yield" (exists (function Yield _ -> true | _ -> false) = built);
+  check "false edge" (exists (function FalseEdge _ -> true | _ -> false) = built);
+  check "false unwind" (exists (function FalseUnwind _ -> true | _ -> false) = built)
+
+let check_asserts mir =
+  let stmts = all_statements mir in
+  check "resumed after return"
+    (List.exists
+       (function Assert { msg = ResumedAfterReturn _; _ } -> true | _ -> false)
+       stmts);
+  let casts =
+    List.filter_map (function (Cast (kind, _, _) : rvalue) -> Some kind | _ -> None)
+      (rvalues (body mir "main"))
+  in
   List.iter
-    (fun file ->
-      let mir = Rustc_parser.Rustc_ast.get_mir file in
-      let pp_mir fmt bodies =
-        List.iter (Rustc_parser.Rustc_pp.pp_body fmt) bodies
-      in
-      let out_file = Filename.chop_extension file ^ ".output" in
-      let oc = open_out out_file in
-      let fmt = Format.formatter_of_out_channel oc in
-      Format.fprintf fmt "%a" pp_mir mir;
-      close_out oc
-    ) rs_files
+    (fun (what, kind) -> check what (List.mem kind casts))
+    [ ("int to int", IntToInt); ("int to float", IntToFloat);
+      ("float to int", FloatToInt); ("float to float", FloatToFloat);
+      ("pointer to pointer", PtrToPtr); ("expose provenance", PointerExposeProvenance);
+      ("with exposed provenance", PointerWithExposedProvenance);
+      ("function pointer to pointer", FnPtrToPtr) ]
+
+let check_pattern_types mir =
+  let percent : typ =
+    Pat (U 8l, Range (Val (ScalarInt ("0x0", 1), U 8l), Val (ScalarInt ("0x64", 1), U 8l)))
+  in
+  check "range pattern" (List.mem percent (local_types mir))
+
+let check_config mir =
+  check "library" (List.for_all (fun (b : body) -> b.header.name <> Some "main") mir.bodies);
+  check "crate-qualified path" ((body mir "extra").header.def_path = "configured::extra");
+  let extra = statements (body mir "extra") in
+  check "no overflow check"
+    (List.for_all (function Assert { msg = Overflow _; _ } -> false | _ -> true) extra);
+  check "unchecked addition"
+    (List.exists (function Assign (_, BinaryOp (Add, _, _)) -> true | _ -> false) extra)
+
+(* The binary of `b` calls the library of `a`, compiled first; the cfgs of
+   the config reach every crate. *)
+let check_workspace manifest =
+  let config = { (default_config ()) with cfgs = ["extra"] } in
+  let crates =
+    match get_workspace_mir manifest config with
+    | Ok crates -> crates
+    | Error msg -> failwith msg
+  in
+  let crate_mir package =
+    match List.find_opt (fun (c : workspace_crate) -> c.package = package) crates with
+    | Some c -> c.crate_mir
+    | None -> failwith ("no crate for package " ^ package)
+  in
+  check "configured library" ((body (crate_mir "a") "extra").header.def_path = "a::extra");
+  check "call to a dependency"
+    (List.exists
+       (function
+         | Call { resolved = Resolved instance; _ } -> instance.def_path = "a::double"
+         | _ -> false)
+       (statements (body (crate_mir "b") "main")))
+
+let () =
+  let file, mode =
+    match Sys.argv with
+    | [| _; file |] -> (file, None)
+    | [| _; file; mode |] -> (file, Some mode)
+    | _ -> failwith "usage: test.exe <file.rs> [<mode>]"
+  in
+  if mode = Some "workspace" then check_workspace file else
+  let mir = extract file mode in
+  match (Filename.chop_extension file, mode) with
+  | "aggregate", None -> check_aggregate mir
+  | "borrow", None -> check_borrow mir
+  | "consts", None -> check_consts ~phase:Optimized mir
+  | "consts", Some "drops-elaborated" -> check_consts ~phase:DropsElaborated mir
+  | "loop", None -> check_loop mir
+  | "promoted", None -> check_promoted ~phase:DropsElaborated mir
+  | "promoted", Some "promoted" -> check_promoted ~phase:Promoted mir
+  | "promoted", Some "drops-elaborated" -> check_promoted ~phase:DropsElaborated mir
+  | "unsafe", None -> check_unsafe mir
+  | "unsafe2", None -> check_unsafe mir
+  | "unsafe_ops", None -> check_unsafe_ops mir
+  | "closures", None -> check_closures mir
+  | "terminators", None -> check_terminators ~built:false mir
+  | "terminators", Some "built" -> check_terminators ~built:true mir
+  | "asserts", None -> check_asserts mir
+  | "pattern_types", None -> check_pattern_types mir
+  | "config", Some "config" -> check_config mir
+  | _ -> failwith ("no checks for " ^ file)
@@ -0,0 +1,33 @@
+use std::arch::asm;
+
+union IntOrFloat {
+    i: u32,
+    f: f32,
+}
+
+static mut COUNTER: u32 = 0;
+
+unsafe extern "C" {
+    static environ: *const *const u8;
+}
+
+fn counter_address() -> *const u32 {
+    &raw const COUNTER
+}
+
+fn main() {
+    let u = IntOrFloat { f: 1.0 };
+    let bits = unsafe { u.i };
+
+    unsafe {
+        COUNTER += bits;
+    }
+
+    let env = unsafe { environ };
+
+    let mut x: u64 = 3;
+    unsafe {
+        asm!("add {0}, 5", inout(reg) x);
+    }
+    let _ = (env, x, counter_address());
+}
@@ -0,0 +1,8 @@
+pub fn double(x: u32) -> u32 {
+    x * 2
+}
+
+#[cfg(extra)]
+pub fn extra() -> u32 {
+    double(21)
+}
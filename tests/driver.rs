@@ -0,0 +1,56 @@
+// Builds a small crate with `mir-parser-driver` as compiler wrapper and
+// reads the MIR it writes. Run with `make test-driver`.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn writes_mir_during_cargo_build() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("driver-sample");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"sample\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("src").join("main.rs"),
+        "fn twice(x: u32) -> u32 {\n    x * 2\n}\n\nfn main() {\n    let _ = twice(21);\n}\n",
+    )
+    .unwrap();
+
+    let status = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()))
+        .args(["build", "--offline"])
+        .current_dir(&dir)
+        .env("RUSTC_WORKSPACE_WRAPPER", env!("CARGO_BIN_EXE_mir-parser-driver"))
+        .env("MIR_PARSER_PHASE", "built")
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let deps = dir.join("target").join("debug").join("deps");
+    let file = std::fs::read_dir(&deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.to_string_lossy().ends_with(".mir.json"))
+        .expect("no MIR written");
+    let mir : serde_json::Value = serde_json::from_str(&std::fs::read_to_string(file).unwrap()).unwrap();
+
+    let bodies = mir["bodies"].as_array().unwrap();
+    let twice = bodies
+        .iter()
+        .find(|body| body["header"]["name"] == "twice")
+        .expect("no body for `twice`");
+    assert_eq!(twice["header"]["def_path"], "sample::twice");
+    assert_eq!(twice["phase"], "Built");
+    assert_eq!(twice["arg_count"], 1);
+    // Records have the field names of the OCaml types.
+    let x = twice["var_debug_info"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|info| info["name"] == "x")
+        .expect("no debug info for `x`");
+    assert_eq!(x["content"], serde_json::json!({ "Place" : { "local" : 1, "proj" : [] } }));
+}